@@ -1,7 +1,15 @@
+use crate::disorder_type::DisorderType;
+use crate::event_type::{EventType, SubEventType};
+use crate::field::Field;
+use crate::inter::{Inter, Interaction};
+use crate::page::Paging;
+use crate::precision::{GeoPrecision, TimePrecision};
 use crate::region::Region;
 use crate::response::AcledData;
-use crate::{Error, Where};
-use chrono::NaiveDate;
+use crate::{timestamp_to_datetime, Error, Where};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
+use std::str::FromStr;
 
 /// This struct is used for specifying the query parameters for the `acled`
 /// endpoint. See <https://apidocs.acleddata.com/acled_endpoint.html#query-filters>.
@@ -26,9 +34,150 @@ pub struct AcledQuery {
     pub region: Where<Region>,
     pub date: Where<NaiveDate>,
     pub timestamp: Where<u64>,
+    /// The first actor involved in the event.
+    pub actor1: Where<String>,
+    /// The second actor involved in the event.
+    pub actor2: Where<String>,
+    /// Actors associated with or identifying `actor1`.
+    pub assoc_actor_1: Where<String>,
+    /// Actors associated with or identifying `actor2`.
+    pub assoc_actor_2: Where<String>,
+    /// The number of reported fatalities.
+    pub fatalities: Where<u32>,
+    /// The type of event.
+    pub event_type: Where<EventType>,
+    /// The subcategory of the event type.
+    pub sub_event_type: Where<SubEventType>,
+    /// The disorder category an event belongs to.
+    pub disorder_type: Where<DisorderType>,
+    /// The numeric ISO 3166-1 code of the country. See [`AcledQuery::iso_from_alpha`].
+    pub iso: Where<u32>,
+    /// The largest sub-national administrative region.
+    pub admin1: Where<String>,
+    /// The second largest sub-national administrative region.
+    pub admin2: Where<String>,
+    /// The third largest sub-national administrative region.
+    pub admin3: Where<String>,
+    /// The name of the location at which the event took place.
+    pub location: Where<String>,
+    /// The latitude of the location. Use [`Where::Between`] together with
+    /// `longitude` to query a bounding box.
+    pub latitude: Where<f64>,
+    /// The longitude of the location.
+    pub longitude: Where<f64>,
+    /// The actor category of `actor1`.
+    pub inter1: Where<Inter>,
+    /// The actor category of `actor2`.
+    pub inter2: Where<Inter>,
+    /// The combined actor categories of both actors.
+    pub interaction: Where<Interaction>,
+    /// Only return events where civilians were (`Some(true)`) or weren't
+    /// (`Some(false)`) the main or only target. See
+    /// [`AcledEvent::civilian_targeting`].
+    pub civilian_targeting: Option<bool>,
+    /// The sources used to record the event.
+    pub source: Where<String>,
+    /// The geographic closeness of the sources to the event, e.g.
+    /// `Subnational` or `New media`.
+    pub source_scale: Where<String>,
+    /// Additional structured information about the event, e.g. `crowd size`.
+    /// Use [`Where::Like`] to match a single tag.
+    pub tags: Where<String>,
+    /// How precisely the location is known.
+    pub geo_precision: Where<GeoPrecision>,
+    /// How precisely the date is known.
+    pub time_precision: Where<TimePrecision>,
+    /// Only return these columns. Use [`crate::Api::get_acled_sparse`] for
+    /// such queries, because [`AcledEvent`] requires all of its columns.
+    pub fields: Option<Vec<Field>>,
+    /// Also return the population exposure columns, e.g.
+    /// [`AcledEvent::population_best`]. Sent as `population=full`.
+    pub population: bool,
+    /// The maximum number of rows returned per page. Defaults to the
+    /// API's own limit of 5000 rows when unset.
+    ///
+    /// See <https://apidocs.acleddata.com/generalities_section.html#adjusting-the-limit-on-the-number-of-rows-returned>
+    pub limit: Option<u32>,
+    /// Stop fetching once at least this many events have been received.
+    /// Any surplus events are discarded. This is not sent to the API.
+    pub max_results: Option<usize>,
+    /// Stop fetching after this many pages. This is not sent to the API.
+    pub max_pages: Option<u32>,
 }
 
 impl AcledQuery {
+    /// A query for all events of the given type.
+    pub fn of_event_type(event_type: EventType) -> AcledQuery {
+        AcledQuery {
+            event_type: Where::Matches(event_type),
+            ..Default::default()
+        }
+    }
+
+    /// A query for all battles.
+    pub fn battles() -> AcledQuery {
+        Self::of_event_type(EventType::Battles)
+    }
+
+    /// A query for all explosions and remote violence.
+    pub fn explosions_remote_violence() -> AcledQuery {
+        Self::of_event_type(EventType::ExplosionsRemoteViolence)
+    }
+
+    /// A query for all violence against civilians.
+    pub fn violence_against_civilians() -> AcledQuery {
+        Self::of_event_type(EventType::ViolenceAgainstCivilians)
+    }
+
+    /// A query for all protests.
+    pub fn protests() -> AcledQuery {
+        Self::of_event_type(EventType::Protests)
+    }
+
+    /// A query for all protests in `country`.
+    pub fn protests_in(country: impl Into<String>) -> AcledQuery {
+        AcledQuery {
+            country: Where::Matches(country.into()),
+            ..Self::protests()
+        }
+    }
+
+    /// A query for all riots.
+    pub fn riots() -> AcledQuery {
+        Self::of_event_type(EventType::Riots)
+    }
+
+    /// A query for all strategic developments.
+    pub fn strategic_developments() -> AcledQuery {
+        Self::of_event_type(EventType::StrategicDevelopments)
+    }
+
+    /// A query for all events in `region` where civilians were the main or
+    /// only target.
+    pub fn civilian_targeting_in(region: Region) -> AcledQuery {
+        AcledQuery {
+            region: Where::Matches(region),
+            civilian_targeting: Some(true),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn paging(&self) -> Paging {
+        Paging {
+            limit: self.limit,
+            max_results: self.max_results,
+            max_pages: self.max_pages,
+        }
+    }
+
+    /// The query parameters that are sent to the API, sorted by name and
+    /// value. This doesn't include the credentials or the `page` parameter.
+    pub fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = self.as_parameters();
+        pairs.sort();
+        pairs
+    }
+
     pub(crate) fn as_parameters(&self) -> Vec<(String, String)> {
         let AcledQuery {
             country,
@@ -37,6 +186,35 @@ impl AcledQuery {
             region,
             date,
             timestamp,
+            actor1,
+            actor2,
+            assoc_actor_1,
+            assoc_actor_2,
+            fatalities,
+            event_type,
+            sub_event_type,
+            disorder_type,
+            iso,
+            admin1,
+            admin2,
+            admin3,
+            location,
+            latitude,
+            longitude,
+            inter1,
+            inter2,
+            interaction,
+            civilian_targeting,
+            source,
+            source_scale,
+            tags,
+            geo_precision,
+            time_precision,
+            fields,
+            population,
+            limit,
+            max_results: _,
+            max_pages: _,
         } = self;
 
         let mut parameters = Vec::new();
@@ -46,6 +224,50 @@ impl AcledQuery {
         parameters.extend_from_slice(&region.as_parameters("region"));
         parameters.extend_from_slice(&date.as_parameters("event_date"));
         parameters.extend_from_slice(&timestamp.as_parameters("timestamp"));
+        parameters.extend_from_slice(&actor1.as_parameters("actor1"));
+        parameters.extend_from_slice(&actor2.as_parameters("actor2"));
+        parameters.extend_from_slice(&assoc_actor_1.as_parameters("assoc_actor_1"));
+        parameters.extend_from_slice(&assoc_actor_2.as_parameters("assoc_actor_2"));
+        parameters.extend_from_slice(&fatalities.as_parameters("fatalities"));
+        parameters.extend_from_slice(&event_type.as_parameters("event_type"));
+        parameters.extend_from_slice(&sub_event_type.as_parameters("sub_event_type"));
+        parameters.extend_from_slice(&disorder_type.as_parameters("disorder_type"));
+        parameters.extend_from_slice(&iso.as_parameters("iso"));
+        parameters.extend_from_slice(&admin1.as_parameters("admin1"));
+        parameters.extend_from_slice(&admin2.as_parameters("admin2"));
+        parameters.extend_from_slice(&admin3.as_parameters("admin3"));
+        parameters.extend_from_slice(&location.as_parameters("location"));
+        parameters.extend_from_slice(&latitude.as_parameters("latitude"));
+        parameters.extend_from_slice(&longitude.as_parameters("longitude"));
+        parameters.extend_from_slice(&inter1.as_parameters("inter1"));
+        parameters.extend_from_slice(&inter2.as_parameters("inter2"));
+        parameters.extend_from_slice(&interaction.as_parameters("interaction"));
+        // Note: The column contains `Civilian targeting` or is empty.
+        let civilian_targeting = match civilian_targeting {
+            Some(true) => Where::Matches(CIVILIAN_TARGETING.to_owned()),
+            Some(false) => Where::NotEqual(CIVILIAN_TARGETING.to_owned()),
+            None => Where::Unspecified,
+        };
+        parameters.extend_from_slice(&civilian_targeting.as_parameters("civilian_targeting"));
+        parameters.extend_from_slice(&source.as_parameters("source"));
+        parameters.extend_from_slice(&source_scale.as_parameters("source_scale"));
+        parameters.extend_from_slice(&tags.as_parameters("tags"));
+        parameters.extend_from_slice(&geo_precision.as_parameters("geo_precision"));
+        parameters.extend_from_slice(&time_precision.as_parameters("time_precision"));
+        if let Some(fields) = fields {
+            let fields = fields
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("|");
+            parameters.push(("fields".into(), fields));
+        }
+        if *population {
+            parameters.push(("population".into(), "full".into()));
+        }
+        if let Some(limit) = limit {
+            parameters.push(("limit".into(), limit.to_string()));
+        }
         parameters
     }
 }
@@ -53,7 +275,7 @@ impl AcledQuery {
 /// An event returned by the `acled` endpoint.
 ///
 /// Descriptions based on <https://apidocs.acleddata.com/acled_endpoint.html>
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct AcledEvent {
     /// A unique alphanumeric event identifier by number and country acronym.
     /// This identifier remains constant even when the event details are updated.
@@ -67,19 +289,33 @@ pub struct AcledEvent {
     ///
     /// Renamed from `event_date`.
     pub date: NaiveDate,
+    /// The year in which the event took place.
+    pub year: u32,
     /// The type of event; further specifies the nature of the event.
-    /// Followed by the subcategory of the event type.
-    ///
-    /// Consist of the renamed `event_type` and `sub_event_type`.
-    pub event_type: (String, String),
+    pub event_type: EventType,
+    /// The subcategory of the event type. Usually belongs to `event_type`,
+    /// see [`AcledEvent::has_consistent_types`].
+    pub sub_event_type: SubEventType,
     /// The disorder category an event belongs to.
-    pub disorder_type: String,
+    pub disorder_type: DisorderType,
     /// The region of the world where the event took place.
     pub region: Region,
     /// The country or territory in which the event took place.
     pub country: String,
+    /// The numeric ISO 3166-1 code of `country`.
+    pub iso: u32,
     /// The sub-national administrative region
     pub administrative_region: String,
+    /// The second-level administrative region, if any.
+    pub admin2: Option<String>,
+    /// The third-level administrative region, if any.
+    pub admin3: Option<String>,
+    /// The name of the location where the event took place, if any.
+    pub location: Option<String>,
+    /// How precisely the location of the event is known.
+    pub geo_precision: GeoPrecision,
+    /// How precisely the date of the event is known.
+    pub time_precision: TimePrecision,
 
     pub latitude: f64,
     pub longitude: f64,
@@ -88,45 +324,323 @@ pub struct AcledEvent {
     ///
     /// Renamed from `notes`.
     pub note: String,
+    /// Whether civilians were the main or only target of the event.
+    ///
+    /// Parsed from the `civilian_targeting` column, which contains
+    /// `Civilian targeting` in that case and is empty otherwise.
+    pub civilian_targeting: bool,
+
+    /// The number of reported fatalities arising from the event.
+    pub fatalities: u32,
+    /// One of the main actors involved in the event.
+    pub actor1: String,
+    /// Actors associated with or identifying `actor1`.
+    pub assoc_actor_1: String,
+    /// The category of `actor1`.
+    pub inter1: Inter,
+    /// One of the main actors involved in the event. Empty if the event
+    /// only has a single actor.
+    pub actor2: String,
+    /// Actors associated with or identifying `actor2`.
+    pub assoc_actor_2: String,
+    /// The category of `actor2`, if there is one.
+    pub inter2: Option<Inter>,
+    /// The combination of `inter1` and `inter2`.
+    pub interaction: Interaction,
+
+    /// The sources used to record the event, separated by `; `.
+    pub source: String,
+    /// The geographic closeness of the sources to the event, e.g. `National`.
+    pub source_scale: String,
+    /// Additional structured information about the event, e.g. the reported
+    /// crowd size of a demonstration.
+    ///
+    /// Split from the `; ` separated `tags` column.
+    pub tags: Vec<String>,
+
+    /// The estimated population within 1 km of the event. Only returned
+    /// when requested with [`AcledQuery::population`].
+    pub population_1km: Option<u64>,
+    /// The estimated population within 2 km of the event.
+    pub population_2km: Option<u64>,
+    /// The estimated population within 5 km of the event.
+    pub population_5km: Option<u64>,
+    /// The best estimate of the population exposed to the event, based on
+    /// its geographic precision.
+    pub population_best: Option<u64>,
+
+    /// Columns returned by the API that this crate doesn't parse yet, e.g.
+    /// new columns added upstream. See also [`crate::Api::diagnose`].
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Identifies one version of an event: the same `id` with a newer
+/// `timestamp` is an update. Unlike [`AcledEvent`] this implements `Eq`,
+/// `Hash` and `Ord`, e.g. for deduplicating events with a `HashSet`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EventKey {
+    pub id: String,
+    pub timestamp: u64,
+}
+
+impl AcledEvent {
+    /// The key of this version of the event.
+    pub fn key(&self) -> EventKey {
+        EventKey {
+            id: self.id.clone(),
+            timestamp: self.timestamp,
+        }
+    }
+
+    /// The `timestamp` as a date and time.
+    pub fn uploaded_at(&self) -> DateTime<Utc> {
+        timestamp_to_datetime(self.timestamp)
+    }
+
+    /// Whether `sub_event_type` belongs to `event_type`, see
+    /// [`SubEventType::belongs_to`]. Events with mismatched types are still
+    /// returned, because ACLED has reclassified subcategories before.
+    pub fn has_consistent_types(&self) -> bool {
+        self.sub_event_type.belongs_to(&self.event_type)
+    }
 }
 
 impl TryFrom<AcledData> for AcledEvent {
     type Error = Error;
 
     fn try_from(data: AcledData) -> Result<Self, Self::Error> {
+        let inter1 = parse_inter1(&data.inter1)?;
+        let inter2 = parse_inter2(&data.inter2)?;
+
         Ok(AcledEvent {
             id: data.event_id_cnty,
-            date: NaiveDate::parse_from_str(&data.event_date, "%Y-%m-%d")
-                .map_err(|_| Error::ParseError("event_date".into()))?,
-            timestamp: data
-                .timestamp
-                .parse()
-                .map_err(|_| Error::ParseError("timestamp".into()))?,
-            event_type: (data.event_type, data.sub_event_type),
-            disorder_type: data.disorder_type,
-            region: data
-                .region
-                .parse()
-                .map_err(|_| Error::ParseError("region".into()))?,
+            date: parse_date(&data.event_date)?,
+            year: parse_column(&data.year, "year")?,
+            timestamp: parse_column(&data.timestamp, "timestamp")?,
+            event_type: parse_event_type(&data.event_type),
+            sub_event_type: parse_sub_event_type(&data.sub_event_type),
+            disorder_type: parse_disorder_type(&data.disorder_type),
+            region: parse_column(&data.region, "region")?,
             administrative_region: data.admin1,
+            admin2: non_empty(data.admin2),
+            admin3: non_empty(data.admin3),
+            location: non_empty(data.location),
+            geo_precision: parse_geo_precision(&data.geo_precision)?,
+            time_precision: parse_time_precision(&data.time_precision)?,
             country: data.country,
-            latitude: data
-                .latitude
-                .parse()
-                .map_err(|_| Error::ParseError("latitude".into()))?,
-            longitude: data
-                .longitude
-                .parse()
-                .map_err(|_| Error::ParseError("longitude".into()))?,
+            iso: parse_column(&data.iso, "iso")?,
+            latitude: parse_coordinate(&data.latitude, "latitude")?,
+            longitude: parse_coordinate(&data.longitude, "longitude")?,
             note: data.notes,
+            civilian_targeting: parse_civilian_targeting(&data.civilian_targeting)?,
+            fatalities: parse_column(&data.fatalities, "fatalities")?,
+            actor1: data.actor1,
+            assoc_actor_1: data.assoc_actor_1,
+            inter1,
+            actor2: data.actor2,
+            assoc_actor_2: data.assoc_actor_2,
+            inter2,
+            interaction: parse_interaction(&data.interaction)?
+                .unwrap_or(Interaction::new(inter1, inter2)),
+            source: data.source,
+            source_scale: data.source_scale,
+            tags: split_tags(&data.tags),
+            population_1km: parse_population(data.population_1km.as_deref()),
+            population_2km: parse_population(data.population_2km.as_deref()),
+            population_5km: parse_population(data.population_5km.as_deref()),
+            population_best: parse_population(data.population_best.as_deref()),
+            extras: data.extras,
         })
     }
 }
 
+// Note: The parsers for the columns of the `acled` endpoint are shared with
+// `SparseEvent`, so both parse every column the same way.
+
+/// Parse a column with [`FromStr`], e.g. a number.
+pub(crate) fn parse_column<T: FromStr>(value: &str, column: &str) -> Result<T, Error> {
+    value.parse().map_err(|_| Error::ParseError(column.into()))
+}
+
+pub(crate) fn parse_date(value: &str) -> Result<NaiveDate, Error> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| Error::ParseError("event_date".into()))
+}
+
+// Note: Parsing the event and disorder types never fails, because unknown
+// values are kept as `Unknown`.
+
+pub(crate) fn parse_event_type(value: &str) -> EventType {
+    value.parse().unwrap()
+}
+
+pub(crate) fn parse_sub_event_type(value: &str) -> SubEventType {
+    value.parse().unwrap()
+}
+
+pub(crate) fn parse_disorder_type(value: &str) -> DisorderType {
+    value.parse().unwrap()
+}
+
+pub(crate) fn parse_geo_precision(value: &str) -> Result<GeoPrecision, Error> {
+    value
+        .parse()
+        .ok()
+        .and_then(GeoPrecision::from_code)
+        .ok_or_else(|| Error::ParseError("geo_precision".into()))
+}
+
+pub(crate) fn parse_time_precision(value: &str) -> Result<TimePrecision, Error> {
+    value
+        .parse()
+        .ok()
+        .and_then(TimePrecision::from_code)
+        .ok_or_else(|| Error::ParseError("time_precision".into()))
+}
+
+/// Every event has a first actor.
+pub(crate) fn parse_inter1(value: &str) -> Result<Inter, Error> {
+    Inter::parse_column(value)
+        .flatten()
+        .ok_or_else(|| Error::ParseError("inter1".into()))
+}
+
+pub(crate) fn parse_inter2(value: &str) -> Result<Option<Inter>, Error> {
+    Inter::parse_column(value).ok_or_else(|| Error::ParseError("inter2".into()))
+}
+
+/// `None` if the column is empty, the interaction then has to be derived
+/// from `inter1` and `inter2`.
+pub(crate) fn parse_interaction(value: &str) -> Result<Option<Interaction>, Error> {
+    Interaction::parse_column(value).ok_or_else(|| Error::ParseError("interaction".into()))
+}
+
+const CIVILIAN_TARGETING: &str = "Civilian targeting";
+
+pub(crate) fn parse_civilian_targeting(value: &str) -> Result<bool, Error> {
+    match value {
+        "" => Ok(false),
+        CIVILIAN_TARGETING => Ok(true),
+        _ => Err(Error::ParseError("civilian_targeting".into())),
+    }
+}
+
+pub(crate) fn non_empty(value: String) -> Option<String> {
+    Some(value).filter(|value| !value.is_empty())
+}
+
+/// Parse a latitude or longitude. Besides the usual format, this accepts
+/// scientific notation and a comma as the decimal separator, which some
+/// historical exports use. The raw value is kept in the error.
+pub(crate) fn parse_coordinate(value: &str, column: &str) -> Result<f64, Error> {
+    let value = value.trim();
+    value
+        .parse()
+        .or_else(|_| value.replacen(',', ".", 1).parse())
+        .map_err(|_| Error::ParseError(format!("{column}: {value:?}")))
+}
+
+/// Population estimates are sometimes empty or formatted as floats, which
+/// are treated as missing or rounded instead of failing the whole event.
+pub(crate) fn parse_population(value: Option<&str>) -> Option<u64> {
+    let value = value?.trim();
+    value.parse().ok().or_else(|| {
+        value
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite() && *value >= 0.0)
+            .map(|value| value.round() as u64)
+    })
+}
+
+pub(crate) fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(';')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(String::from)
+        .collect()
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
+    /// A complete row of the `acled` endpoint.
+    pub(crate) fn acled_row(id: &str, timestamp: u64) -> serde_json::Value {
+        serde_json::json!({
+            "event_id_cnty": id,
+            "event_date": "2024-03-01",
+            "timestamp": timestamp.to_string(),
+            "disorder_type": "Political violence",
+            "event_type": "Battles",
+            "sub_event_type": "Armed clash",
+            "country": "Mali",
+            "region": "Western Africa",
+            "admin1": "Mopti",
+            "latitude": "14.4843",
+            "longitude": "-4.1960",
+            "notes": "",
+            "civilian_targeting": "",
+            "actor1": "Military Forces of Mali (2021-)",
+            "assoc_actor_1": "",
+            "inter1": "1",
+            "actor2": "JNIM: Group for Support of Islam and Muslims",
+            "assoc_actor_2": "",
+            "inter2": "2",
+            "interaction": "12",
+            "fatalities": "3",
+            "source": "Journal du Mali",
+            "source_scale": "National",
+            "tags": "crowd size=no report; local administrators",
+            "admin2": "Djenne",
+            "admin3": "",
+            "location": "Djenne",
+            "geo_precision": "1",
+            "time_precision": "1",
+            "year": "2024",
+            "iso": "466",
+            "new_column": "new",
+        })
+    }
+
+    /// An event parsed from [`acled_row`].
+    pub(crate) fn acled_event(id: &str) -> AcledEvent {
+        serde_json::from_value::<AcledData>(acled_row(id, 1710025200))
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn inconsistent_types_test() {
+        let event = acled_event("MLI1");
+        assert!(event.has_consistent_types());
+
+        let mut row = acled_row("MLI1", 1710025200);
+        row["sub_event_type"] = "Peaceful protest".into();
+        let event: AcledEvent = serde_json::from_value::<AcledData>(row)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(event.sub_event_type, SubEventType::PeacefulProtest);
+        assert!(!event.has_consistent_types());
+    }
+
+    #[test]
+    fn interaction_test() {
+        let parse = |interaction: &str| {
+            let mut row = acled_row("MLI1", 1710025200);
+            row["interaction"] = interaction.into();
+            AcledEvent::try_from(serde_json::from_value::<AcledData>(row).unwrap())
+        };
+        let derived = Interaction::new(Inter::StateForces, Some(Inter::RebelGroups));
+        assert_eq!(parse("12").unwrap().interaction, derived);
+        assert_eq!(parse("").unwrap().interaction, derived);
+        assert!(matches!(
+            parse("State forces-Rebel groups"),
+            Err(Error::ParseError(column)) if column == "interaction"
+        ));
+    }
+
     #[test]
     fn query_test() {
         let query = AcledQuery {
@@ -149,7 +663,7 @@ mod tests {
 
         let query = AcledQuery {
             region: Where::Matches(Region::MiddleAfrica),
-            date: Where::GreaterThan(NaiveDate::from_ymd_opt(2024, 02, 28).unwrap()),
+            date: Where::GreaterThan(NaiveDate::from_ymd_opt(2024, 2, 28).unwrap()),
             ..Default::default()
         };
         assert_eq!(
@@ -160,5 +674,370 @@ mod tests {
                 ("event_date".into(), "2024-02-28".into())
             ]
         );
+
+        let query = AcledQuery {
+            country: Where::Matches("Mali".into()),
+            limit: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("country".into(), "Mali".into()),
+                ("limit".into(), "100".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            actor1: Where::Like("Wagner Group*".into()),
+            assoc_actor_2: Where::Matches("Civilians (Mali)".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("actor1_where".into(), "LIKE".into()),
+                ("actor1".into(), "Wagner Group*".into()),
+                ("assoc_actor_2".into(), "Civilians (Mali)".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            fatalities: Where::GreaterThanOrEqual(10),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("fatalities_where".into(), ">=".into()),
+                ("fatalities".into(), "10".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            event_type: Where::Matches(EventType::ExplosionsRemoteViolence),
+            sub_event_type: Where::Matches(SubEventType::AirDroneStrike),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("event_type".into(), "Explosions/Remote violence".into()),
+                ("sub_event_type".into(), "Air/drone strike".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            disorder_type: Where::Matches(DisorderType::PoliticalViolence),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![("disorder_type".into(), "Political violence".into())]
+        );
+
+        let query = AcledQuery {
+            iso: Where::Matches(180),
+            ..Default::default()
+        };
+        assert_eq!(query.as_parameters(), vec![("iso".into(), "180".into())]);
+
+        let query = AcledQuery {
+            admin1: Where::Matches("Mopti".into()),
+            location: Where::Like("*Bandiagara*".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("admin1".into(), "Mopti".into()),
+                ("location_where".into(), "LIKE".into()),
+                ("location".into(), "*Bandiagara*".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            latitude: Where::Between(12.5, 12.75),
+            longitude: Where::Between(-8.125, -7.875),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("latitude_where".into(), "BETWEEN".into()),
+                ("latitude".into(), "12.5|12.75".into()),
+                ("longitude_where".into(), "BETWEEN".into()),
+                ("longitude".into(), "-8.125|-7.875".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            inter1: Where::Matches(Inter::StateForces),
+            interaction: Where::Matches(Interaction::new(
+                Inter::Civilians,
+                Some(Inter::StateForces),
+            )),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("inter1".into(), "1".into()),
+                ("interaction".into(), "17".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            interaction: Where::Matches(Interaction::new(Inter::Protesters, None)),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![("interaction".into(), "60".into())]
+        );
+
+        let query = AcledQuery {
+            civilian_targeting: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![("civilian_targeting".into(), "Civilian targeting".into())]
+        );
+
+        let query = AcledQuery {
+            civilian_targeting: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("civilian_targeting_where".into(), "!=".into()),
+                ("civilian_targeting".into(), "Civilian targeting".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            source_scale: Where::Matches("New media".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![("source_scale".into(), "New media".into())]
+        );
+
+        let query = AcledQuery {
+            tags: Where::Like("*drone*".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("tags_where".into(), "LIKE".into()),
+                ("tags".into(), "*drone*".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            geo_precision: Where::Equal(GeoPrecision::Exact),
+            time_precision: Where::Equal(TimePrecision::Day),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("geo_precision_where".into(), "=".into()),
+                ("geo_precision".into(), "1".into()),
+                ("time_precision_where".into(), "=".into()),
+                ("time_precision".into(), "1".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            country: Where::In(vec!["Mali".into(), "Niger".into(), "Burkina Faso".into()]),
+            event_type: Where::In(Vec::new()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![(
+                "country".into(),
+                "Mali:OR:country=Niger:OR:country=Burkina Faso".into()
+            )]
+        );
+
+        let query = AcledQuery {
+            event_type: Where::NotEqual(EventType::Protests),
+            actor1: Where::NotLike("*Police*".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("actor1_where".into(), "NOT LIKE".into()),
+                ("actor1".into(), "*Police*".into()),
+                ("event_type_where".into(), "!=".into()),
+                ("event_type".into(), "Protests".into())
+            ]
+        );
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let query = AcledQuery {
+            date: (start..end).into(),
+            year: (2020..=2023).into(),
+            fatalities: (1..).into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("year_where".into(), "BETWEEN".into()),
+                ("year".into(), "2020|2023".into()),
+                ("event_date_where".into(), "BETWEEN".into()),
+                ("event_date".into(), "2024-01-01|2024-01-31".into()),
+                ("fatalities_where".into(), ">=".into()),
+                ("fatalities".into(), "1".into())
+            ]
+        );
+
+        let query = AcledQuery {
+            fields: Some(vec![
+                Field::Id,
+                Field::Date,
+                Field::Actor1,
+                Field::AssocActor1,
+                Field::Admin2,
+                Field::GeoPrecision,
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![(
+                "fields".into(),
+                "event_id_cnty|event_date|actor1|assoc_actor_1|admin2|geo_precision".into()
+            )]
+        );
+    }
+
+    #[test]
+    fn split_tags_test() {
+        assert_eq!(
+            split_tags("crowd size=no report; local administrators"),
+            vec!["crowd size=no report", "local administrators"]
+        );
+        assert_eq!(split_tags(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn non_empty_test() {
+        assert_eq!(non_empty("Djenne".into()), Some("Djenne".into()));
+        assert_eq!(non_empty(String::new()), None);
+    }
+
+    #[test]
+    fn query_pairs_snapshot_test() {
+        let query = AcledQuery {
+            country: Where::In(vec!["Mali".into(), "Niger".into()]),
+            date: Where::Between(
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            ),
+            event_type: Where::NotEqual(EventType::Protests),
+            fatalities: Where::GreaterThan(0),
+            fields: Some(vec![Field::Id, Field::Date]),
+            limit: Some(500),
+            max_results: Some(1000),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.to_query_pairs(),
+            vec![
+                ("country".into(), "Mali:OR:country=Niger".into()),
+                ("event_date".into(), "2024-01-01|2024-03-31".into()),
+                ("event_date_where".into(), "BETWEEN".into()),
+                ("event_type".into(), "Protests".into()),
+                ("event_type_where".into(), "!=".into()),
+                ("fatalities".into(), "0".into()),
+                ("fatalities_where".into(), ">".into()),
+                ("fields".into(), "event_id_cnty|event_date".into()),
+                ("limit".into(), "500".into())
+            ]
+        );
+        assert_eq!(AcledQuery::default().to_query_pairs(), vec![]);
+    }
+
+    #[test]
+    fn civilian_targeting_test() {
+        assert!(parse_civilian_targeting("Civilian targeting").unwrap());
+        assert!(!parse_civilian_targeting("").unwrap());
+        assert!(parse_civilian_targeting("Yes").is_err());
+    }
+
+    #[test]
+    fn constructor_test() {
+        assert_eq!(
+            AcledQuery::protests_in("Mali").to_query_pairs(),
+            vec![
+                ("country".into(), "Mali".into()),
+                ("event_type".into(), "Protests".into())
+            ]
+        );
+        assert_eq!(
+            AcledQuery::civilian_targeting_in(Region::WesternAfrica).to_query_pairs(),
+            vec![
+                ("civilian_targeting".into(), "Civilian targeting".into()),
+                ("region".into(), "1".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn population_test() {
+        assert_eq!(parse_population(Some("1234")), Some(1234));
+        assert_eq!(parse_population(Some("1234.6")), Some(1235));
+        assert_eq!(parse_population(Some("")), None);
+        assert_eq!(parse_population(Some("-1")), None);
+        assert_eq!(parse_population(None), None);
+
+        let query = AcledQuery {
+            population: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![("population".into(), "full".into())]
+        );
+    }
+
+    #[test]
+    fn coordinate_test() {
+        assert_eq!(parse_coordinate("14.4843", "latitude").unwrap(), 14.4843);
+        assert_eq!(parse_coordinate("-4,1960", "longitude").unwrap(), -4.196);
+        assert_eq!(parse_coordinate("1.44843e1", "latitude").unwrap(), 14.4843);
+        assert!(matches!(
+            parse_coordinate("14,48,43", "latitude"),
+            Err(Error::ParseError(message)) if message == r#"latitude: "14,48,43""#
+        ));
+    }
+
+    #[test]
+    fn key_test() {
+        let event = acled_event("MLI1");
+        assert_eq!(event.year, 2024);
+        assert_eq!(event.iso, 466);
+        assert!(event.extras.keys().eq(["new_column"]));
+        let mut updated = event.clone();
+        updated.timestamp += 1;
+        assert_eq!(event, event.clone());
+        assert_ne!(event, updated);
+
+        let keys: std::collections::HashSet<_> = [&event, &event, &updated]
+            .iter()
+            .map(|event| event.key())
+            .collect();
+        assert_eq!(keys.len(), 2);
+        assert!(event.key() < updated.key());
     }
 }
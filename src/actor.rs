@@ -0,0 +1,149 @@
+use crate::{page::Paging, response::ActorData, Error, Where};
+use chrono::NaiveDate;
+
+/// This struct is used for specifying the query parameters for the `actor`
+/// endpoint. See <https://apidocs.acleddata.com/actor_endpoint.html#query-filters>.
+///
+/// All fields are optional and can be default initialized.
+///
+/// ```
+/// use acled_api::{ActorQuery, Where};
+///
+/// let query = ActorQuery {
+///   name: Where::Like("Wagner Group*".into()),
+///   ..Default::default()
+/// };
+/// ```
+#[derive(Default)]
+pub struct ActorQuery {
+    /// Sent as `actor_name`.
+    pub name: Where<String>,
+    pub first_event_date: Where<NaiveDate>,
+    pub last_event_date: Where<NaiveDate>,
+    pub event_count: Where<u32>,
+    /// The maximum number of rows returned per page. Defaults to the
+    /// API's own limit of 5000 rows when unset.
+    pub limit: Option<u32>,
+    /// Stop fetching once at least this many actors have been received.
+    /// Any surplus actors are discarded. This is not sent to the API.
+    pub max_results: Option<usize>,
+    /// Stop fetching after this many pages. This is not sent to the API.
+    pub max_pages: Option<u32>,
+}
+
+impl ActorQuery {
+    pub(crate) fn paging(&self) -> Paging {
+        Paging {
+            limit: self.limit,
+            max_results: self.max_results,
+            max_pages: self.max_pages,
+        }
+    }
+
+    /// The query parameters that are sent to the API, sorted by name and
+    /// value. This doesn't include the credentials or the `page` parameter.
+    pub fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = self.as_parameters();
+        pairs.sort();
+        pairs
+    }
+
+    pub(crate) fn as_parameters(&self) -> Vec<(String, String)> {
+        let ActorQuery {
+            name,
+            first_event_date,
+            last_event_date,
+            event_count,
+            limit,
+            max_results: _,
+            max_pages: _,
+        } = self;
+
+        let mut parameters = Vec::new();
+        parameters.extend_from_slice(&name.as_parameters("actor_name"));
+        parameters.extend_from_slice(&first_event_date.as_parameters("first_event_date"));
+        parameters.extend_from_slice(&last_event_date.as_parameters("last_event_date"));
+        parameters.extend_from_slice(&event_count.as_parameters("event_count"));
+        if let Some(limit) = limit {
+            parameters.push(("limit".into(), limit.to_string()));
+        }
+        parameters
+    }
+}
+
+/// An actor returned by the `actor` endpoint.
+///
+/// Descriptions based on <https://apidocs.acleddata.com/actor_endpoint.html>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Actor {
+    /// The name of the actor, as used in the `actor1` and `actor2` columns.
+    ///
+    /// Renamed from `actor_name`.
+    pub name: String,
+    /// The date of the earliest event involving the actor.
+    pub first_event_date: NaiveDate,
+    /// The date of the latest event involving the actor.
+    pub last_event_date: NaiveDate,
+    /// The number of events involving the actor.
+    pub event_count: u32,
+}
+
+impl TryFrom<ActorData> for Actor {
+    type Error = Error;
+
+    fn try_from(data: ActorData) -> Result<Self, Self::Error> {
+        Ok(Actor {
+            name: data.actor_name,
+            first_event_date: NaiveDate::parse_from_str(&data.first_event_date, "%Y-%m-%d")
+                .map_err(|_| Error::ParseError("first_event_date".into()))?,
+            last_event_date: NaiveDate::parse_from_str(&data.last_event_date, "%Y-%m-%d")
+                .map_err(|_| Error::ParseError("last_event_date".into()))?,
+            event_count: data
+                .event_count
+                .parse()
+                .map_err(|_| Error::ParseError("event_count".into()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    #[test]
+    fn query_test() {
+        let query = ActorQuery {
+            name: Where::Like("Wagner Group*".into()),
+            event_count: Where::GreaterThanOrEqual(100),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("actor_name_where".into(), "LIKE".into()),
+                ("actor_name".into(), "Wagner Group*".into()),
+                ("event_count_where".into(), ">=".into()),
+                ("event_count".into(), "100".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn actor_test() {
+        let body = br#"{"success":true,"count":1,"data":[{"actor_name":"Wagner Group","first_event_date":"2017-12-03","last_event_date":"2024-03-01","event_count":"1234"}]}"#;
+        let actors = serde_json::from_slice::<Response<ActorData>>(body)
+            .unwrap()
+            .into::<Actor>()
+            .unwrap();
+        assert_eq!(
+            actors,
+            vec![Actor {
+                name: "Wagner Group".into(),
+                first_event_date: NaiveDate::from_ymd_opt(2017, 12, 3).unwrap(),
+                last_event_date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                event_count: 1234,
+            }]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{inter::Inter, page::Paging, response::ActorTypeData, Error, Where};
+use chrono::NaiveDate;
+
+/// This struct is used for specifying the query parameters for the `actortype`
+/// endpoint. See <https://apidocs.acleddata.com/actortype_endpoint.html#query-filters>.
+///
+/// All fields are optional and can be default initialized.
+///
+/// ```
+/// use acled_api::{ActorTypeQuery, Where};
+///
+/// let query = ActorTypeQuery {
+///   name: Where::Like("*militias".into()),
+///   ..Default::default()
+/// };
+/// ```
+#[derive(Default)]
+pub struct ActorTypeQuery {
+    /// Sent as `actor_type_id`.
+    pub id: Where<u32>,
+    /// Sent as `actor_type_name`.
+    pub name: Where<String>,
+    pub first_event_date: Where<NaiveDate>,
+    pub last_event_date: Where<NaiveDate>,
+    pub event_count: Where<u32>,
+    /// The maximum number of rows returned per page. Defaults to the
+    /// API's own limit of 5000 rows when unset.
+    pub limit: Option<u32>,
+    /// Stop fetching once at least this many actor types have been received.
+    /// Any surplus actor types are discarded. This is not sent to the API.
+    pub max_results: Option<usize>,
+    /// Stop fetching after this many pages. This is not sent to the API.
+    pub max_pages: Option<u32>,
+}
+
+impl ActorTypeQuery {
+    pub(crate) fn paging(&self) -> Paging {
+        Paging {
+            limit: self.limit,
+            max_results: self.max_results,
+            max_pages: self.max_pages,
+        }
+    }
+
+    /// The query parameters that are sent to the API, sorted by name and
+    /// value. This doesn't include the credentials or the `page` parameter.
+    pub fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = self.as_parameters();
+        pairs.sort();
+        pairs
+    }
+
+    pub(crate) fn as_parameters(&self) -> Vec<(String, String)> {
+        let ActorTypeQuery {
+            id,
+            name,
+            first_event_date,
+            last_event_date,
+            event_count,
+            limit,
+            max_results: _,
+            max_pages: _,
+        } = self;
+
+        let mut parameters = Vec::new();
+        parameters.extend_from_slice(&id.as_parameters("actor_type_id"));
+        parameters.extend_from_slice(&name.as_parameters("actor_type_name"));
+        parameters.extend_from_slice(&first_event_date.as_parameters("first_event_date"));
+        parameters.extend_from_slice(&last_event_date.as_parameters("last_event_date"));
+        parameters.extend_from_slice(&event_count.as_parameters("event_count"));
+        if let Some(limit) = limit {
+            parameters.push(("limit".into(), limit.to_string()));
+        }
+        parameters
+    }
+}
+
+/// An actor category returned by the `actortype` endpoint.
+///
+/// Descriptions based on <https://apidocs.acleddata.com/actortype_endpoint.html>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ActorType {
+    /// The numeric code of the category, as used in the `inter1` and
+    /// `inter2` columns.
+    ///
+    /// Renamed from `actor_type_id`.
+    pub id: u32,
+    /// The name of the category.
+    ///
+    /// Renamed from `actor_type_name`.
+    pub name: String,
+    /// The date of the earliest event involving an actor of this category.
+    pub first_event_date: NaiveDate,
+    /// The date of the latest event involving an actor of this category.
+    pub last_event_date: NaiveDate,
+    /// The number of events involving an actor of this category.
+    pub event_count: u32,
+}
+
+impl ActorType {
+    /// The category as an [`Inter`], if it is one of the known codes.
+    pub fn inter(&self) -> Option<Inter> {
+        u8::try_from(self.id).ok().and_then(Inter::from_code)
+    }
+}
+
+impl TryFrom<ActorTypeData> for ActorType {
+    type Error = Error;
+
+    fn try_from(data: ActorTypeData) -> Result<Self, Self::Error> {
+        Ok(ActorType {
+            id: data
+                .actor_type_id
+                .parse()
+                .map_err(|_| Error::ParseError("actor_type_id".into()))?,
+            name: data.actor_type_name,
+            first_event_date: NaiveDate::parse_from_str(&data.first_event_date, "%Y-%m-%d")
+                .map_err(|_| Error::ParseError("first_event_date".into()))?,
+            last_event_date: NaiveDate::parse_from_str(&data.last_event_date, "%Y-%m-%d")
+                .map_err(|_| Error::ParseError("last_event_date".into()))?,
+            event_count: data
+                .event_count
+                .parse()
+                .map_err(|_| Error::ParseError("event_count".into()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    #[test]
+    fn query_test() {
+        let query = ActorTypeQuery {
+            id: Where::Matches(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![("actor_type_id".into(), "3".into())]
+        );
+    }
+
+    #[test]
+    fn actor_type_test() {
+        let body = br#"{"success":true,"count":1,"data":[{"actor_type_id":"3","actor_type_name":"Political militias","first_event_date":"1997-01-01","last_event_date":"2024-03-01","event_count":"98765"}]}"#;
+        let actor_types = serde_json::from_slice::<Response<ActorTypeData>>(body)
+            .unwrap()
+            .into::<ActorType>()
+            .unwrap();
+        assert_eq!(actor_types[0].name, "Political militias");
+        assert_eq!(actor_types[0].inter(), Some(Inter::PoliticalMilitias));
+        assert_eq!(actor_types[0].event_count, 98765);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::auth::Auth;
+use crate::failover::BaseUrls;
+use crate::throttle::Throttle;
+#[cfg(feature = "reqwest")]
+use crate::ReqwestTransport;
+use crate::{Api, Configuration, Credentials, Error, HttpTransport};
+use std::time::Duration;
+
+/// Builds an [`Api`] with all its options in one place, and checks them
+/// before any request is made.
+///
+/// ```
+/// use acled_api::{Api, Configuration};
+/// use std::time::Duration;
+///
+/// let api = Api::builder()
+///   .configuration(Configuration {
+///     key: "XXXXX".into(),
+///     email: "foo@example.com".into(),
+///   })
+///   .timeout(Duration::from_secs(30))
+///   .retries(3)
+///   .build()
+///   .unwrap();
+/// ```
+#[derive(Default)]
+pub struct ApiBuilder {
+    credentials: Option<Credentials>,
+    base_urls: Option<Vec<String>>,
+    failover: Option<(u32, Duration)>,
+    transport: Option<Box<dyn HttpTransport>>,
+    #[cfg(feature = "reqwest")]
+    timeout: Option<Duration>,
+    retries: u32,
+    max_bandwidth: Option<u64>,
+    headers: Vec<(String, String)>,
+}
+
+/// The headers sent with every request by default, i.e. just the
+/// `User-Agent` of this crate.
+pub(crate) fn default_headers() -> Vec<(String, String)> {
+    vec![(
+        "User-Agent".into(),
+        concat!("acled_api/", env!("CARGO_PKG_VERSION")).into(),
+    )]
+}
+
+/// Whether `name` and `value` are a valid HTTP header, see RFC 9110.
+fn is_valid_header(name: &str, value: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+        && value.chars().all(|c| c == '\t' || (' '..='~').contains(&c))
+}
+
+impl Api {
+    /// Start building an [`Api`]. See [`ApiBuilder`].
+    pub fn builder() -> ApiBuilder {
+        ApiBuilder::default()
+    }
+}
+
+impl ApiBuilder {
+    /// The credentials used for every request. Required.
+    pub fn credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Shorthand for [`Credentials::Key`].
+    pub fn configuration(self, configuration: Configuration) -> Self {
+        self.credentials(Credentials::Key(configuration))
+    }
+
+    /// See [`Api::with_base_url`].
+    pub fn base_url(self, base_url: impl Into<String>) -> Self {
+        self.base_urls([base_url])
+    }
+
+    /// See [`Api::with_base_urls`].
+    pub fn base_urls<S: Into<String>>(mut self, base_urls: impl IntoIterator<Item = S>) -> Self {
+        self.base_urls = Some(base_urls.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// See [`Api::with_failover`].
+    pub fn failover(mut self, threshold: u32, probe_interval: Duration) -> Self {
+        self.failover = Some((threshold, probe_interval));
+        self
+    }
+
+    /// Send requests with a custom [`HttpTransport`] instead of the default
+    /// [`ReqwestTransport`]. Required without the `reqwest` feature.
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Use an existing HTTP client, e.g. one with a proxy configured. Can't
+    /// be combined with [`ApiBuilder::timeout`], configure the timeout on the
+    /// client instead.
+    #[cfg(feature = "reqwest")]
+    pub fn client(self, client: reqwest::blocking::Client) -> Self {
+        self.transport(ReqwestTransport::from(client))
+    }
+
+    /// The timeout of every single request, including reading the body.
+    /// By default requests time out after 30 seconds.
+    #[cfg(feature = "reqwest")]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// See [`Api::with_retries`].
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// See [`Api::with_max_bandwidth`].
+    pub fn max_bandwidth(mut self, bytes_per_second: u64) -> Self {
+        self.max_bandwidth = Some(bytes_per_second);
+        self
+    }
+
+    /// Identify your application with the `User-Agent` header, e.g.
+    /// `"my-dashboard/1.0 (data@example.org)"`. By default it is
+    /// `acled_api/<version>`.
+    pub fn user_agent(self, user_agent: impl Into<String>) -> Self {
+        self.header("User-Agent", user_agent)
+    }
+
+    /// Send an additional header with every request. Setting the same header
+    /// again replaces it.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Finish building the [`Api`]. Fails with [`Error::InvalidConfiguration`]
+    /// if the credentials are missing or incomplete, a header or base URL is
+    /// invalid, or no transport is set without the `reqwest` feature.
+    pub fn build(self) -> Result<Api, Error> {
+        let invalid = |message: &str| Err(Error::InvalidConfiguration(message.into()));
+
+        let credentials = match self.credentials {
+            None => return invalid("no credentials"),
+            Some(Credentials::Key(config)) if config.key.is_empty() => {
+                return invalid("the key is empty")
+            }
+            Some(Credentials::Key(config)) if config.email.is_empty() => {
+                return invalid("the email is empty")
+            }
+            Some(Credentials::OAuth { username, .. }) if username.is_empty() => {
+                return invalid("the username is empty")
+            }
+            Some(Credentials::OAuth { password, .. }) if password.is_empty() => {
+                return invalid("the password is empty")
+            }
+            Some(credentials) => credentials,
+        };
+
+        #[cfg(feature = "reqwest")]
+        let transport: Box<dyn HttpTransport> = match (self.transport, self.timeout) {
+            (Some(_), Some(_)) => return invalid("a timeout can't be set for a custom transport"),
+            (Some(transport), None) => transport,
+            (None, Some(timeout)) => Box::new(ReqwestTransport::from(
+                reqwest::blocking::Client::builder()
+                    .timeout(timeout)
+                    .build()?,
+            )),
+            (None, None) => Box::new(ReqwestTransport::default()),
+        };
+        #[cfg(not(feature = "reqwest"))]
+        let Some(transport) = self.transport
+        else {
+            return invalid("no transport");
+        };
+
+        let mut headers = default_headers();
+        for (name, value) in self.headers {
+            if !is_valid_header(&name, &value) {
+                return Err(Error::InvalidConfiguration(format!(
+                    "invalid header `{name}`"
+                )));
+            }
+            headers.retain(|(header, _)| !header.eq_ignore_ascii_case(&name));
+            headers.push((name, value));
+        }
+
+        let mut base = match self.base_urls {
+            Some(urls) => BaseUrls::new(urls)?,
+            None => BaseUrls::new(vec![credentials.base().to_owned()])?,
+        };
+        if let Some((threshold, probe_interval)) = self.failover {
+            base = base.with_failover(threshold, probe_interval);
+        }
+        Ok(Api {
+            auth: Auth::new(credentials),
+            transport,
+            headers,
+            base,
+            retries: self.retries,
+            throttle: self.max_bandwidth.map(Throttle::new),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::tests::MockTransport;
+
+    fn header<'a>(api: &'a Api, name: &str) -> &'a str {
+        let (_, value) = api
+            .headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .unwrap();
+        value
+    }
+
+    fn configuration(key: &str, email: &str) -> Configuration {
+        Configuration {
+            key: key.into(),
+            email: email.into(),
+        }
+    }
+
+    fn builder() -> ApiBuilder {
+        Api::builder().transport(MockTransport::new(Vec::<(u16, String)>::new()))
+    }
+
+    #[test]
+    fn build_test() {
+        let api = builder()
+            .configuration(configuration("XXXXX", "foo@example.com"))
+            .base_url("http://localhost:8080/")
+            .retries(2)
+            .build()
+            .unwrap();
+        assert_eq!(api.base.pick(), (0, "http://localhost:8080"));
+        assert_eq!(api.retries, 2);
+        assert_eq!(
+            header(&api, "user-agent"),
+            concat!("acled_api/", env!("CARGO_PKG_VERSION"))
+        );
+
+        let api = builder()
+            .configuration(configuration("XXXXX", "foo@example.com"))
+            .user_agent("my-dashboard/1.0")
+            .header("X-Project", "mali")
+            .build()
+            .unwrap();
+        assert_eq!(header(&api, "user-agent"), "my-dashboard/1.0");
+        assert_eq!(header(&api, "x-project"), "mali");
+        assert_eq!(api.headers.len(), 2);
+
+        let error = |builder: ApiBuilder| builder.build().err().unwrap().to_string();
+        assert_eq!(error(builder()), "invalid configuration: no credentials");
+        assert_eq!(
+            error(builder().configuration(configuration("", "foo@example.com"))),
+            "invalid configuration: the key is empty"
+        );
+        assert_eq!(
+            error(
+                builder()
+                    .configuration(configuration("XXXXX", "foo@example.com"))
+                    .header("X Project", "mali")
+            ),
+            "invalid configuration: invalid header `X Project`"
+        );
+        assert_eq!(
+            error(
+                builder()
+                    .configuration(configuration("XXXXX", "foo@example.com"))
+                    .base_urls(Vec::<String>::new())
+            ),
+            "invalid configuration: no base URL"
+        );
+        assert_eq!(
+            error(
+                builder()
+                    .configuration(configuration("XXXXX", "foo@example.com"))
+                    .base_url("localhost:8080")
+            ),
+            "invalid configuration: invalid base URL `localhost:8080`"
+        );
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn timeout_test() {
+        let error = builder()
+            .configuration(configuration("XXXXX", "foo@example.com"))
+            .timeout(Duration::from_secs(1))
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "invalid configuration: a timeout can't be set for a custom transport"
+        );
+    }
+}
@@ -0,0 +1,463 @@
+use crate::response::{AcledData, DeletedData};
+use crate::{parse_page, AcledEvent, DeletedEvent, Error};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// A directory in which raw API responses are stored, keyed by the SHA-256
+/// hash of their content. Together with a [`Manifest`] this allows exactly
+/// reproducing the input data of an analysis later.
+///
+/// ```no_run
+/// use acled_api::{AcledQuery, Api, Archive, Configuration, Where};
+/// # let api = Api::new(Configuration { key: "XXXXX".into(), email: "foo@example.com".into() });
+/// let archive = Archive::new("acled-archive");
+/// let query = AcledQuery {
+///   country: Where::Matches("Mali".into()),
+///   ..Default::default()
+/// };
+/// let (events, manifest) = api.get_acled_archived(&query, &archive).unwrap();
+/// manifest.save("mali.manifest.json").unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Archive {
+    dir: PathBuf,
+}
+
+impl Archive {
+    pub fn new(dir: impl Into<PathBuf>) -> Archive {
+        Archive { dir: dir.into() }
+    }
+
+    /// The directory the pages are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Store a raw page and return its hash. Pages that already exist are
+    /// not written again.
+    pub(crate) fn store_page(&self, body: &[u8]) -> Result<String, Error> {
+        let hash = sha256_hex(body);
+        let path = self.page_path(&hash)?;
+        if !path.exists() {
+            fs::create_dir_all(&self.dir)?;
+            fs::write(path, body)?;
+        }
+        Ok(hash)
+    }
+
+    /// The path of the page with the given hash. Hashes from a manifest
+    /// can't be trusted, so anything but a SHA-256 hash in lowercase hex is
+    /// rejected, e.g. `../` to read files outside the archive.
+    pub(crate) fn page_path(&self, hash: &str) -> Result<PathBuf, Error> {
+        let is_valid = hash.len() == 64
+            && hash
+                .bytes()
+                .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte));
+        if !is_valid {
+            return Err(Error::ArchiveError(format!("invalid page hash {hash:?}")));
+        }
+        Ok(self.dir.join(format!("{hash}.json")))
+    }
+
+    /// Reconstruct the events of an archived pull of the `acled` endpoint
+    /// without making any requests. The content of every page is verified
+    /// against its hash.
+    pub fn replay_acled(&self, manifest: &Manifest) -> Result<Vec<AcledEvent>, Error> {
+        self.replay::<AcledData, _>("acled", manifest)
+    }
+
+    /// Reconstruct the events of an archived pull of the `deleted` endpoint.
+    /// See [`Archive::replay_acled`].
+    pub fn replay_deleted(&self, manifest: &Manifest) -> Result<Vec<DeletedEvent>, Error> {
+        self.replay::<DeletedData, _>("deleted", manifest)
+    }
+
+    /// Compare two archived pulls of the `acled` endpoint and return only the
+    /// events that were added, updated or deleted between them.
+    ///
+    /// Events are matched by their id, an event counts as updated when its
+    /// timestamp changed.
+    pub fn delta_acled(&self, old: &Manifest, new: &Manifest) -> Result<Delta<AcledEvent>, Error> {
+        Ok(Delta::new(
+            self.replay_acled(old)?,
+            self.replay_acled(new)?,
+            |event| (&event.id, event.timestamp),
+        ))
+    }
+
+    /// Write the difference between two archived pulls of the `acled`
+    /// endpoint to `writer` as newline-delimited JSON, one [`DeltaRecord`]
+    /// per line: first the added, then the updated events, as returned by
+    /// the API, followed by the ids of the deleted events.
+    ///
+    /// See [`Archive::delta_acled`] for how events are matched.
+    ///
+    /// ```no_run
+    /// use acled_api::{Archive, Manifest};
+    /// let archive = Archive::new("acled-archive");
+    /// let old = Manifest::load("2024-03.manifest.json").unwrap();
+    /// let new = Manifest::load("2024-04.manifest.json").unwrap();
+    /// let file = std::fs::File::create("delta.ndjson").unwrap();
+    /// archive.export_delta(&old, &new, std::io::BufWriter::new(file)).unwrap();
+    /// ```
+    pub fn export_delta(
+        &self,
+        old: &Manifest,
+        new: &Manifest,
+        mut writer: impl Write,
+    ) -> Result<(), Error> {
+        let delta = Delta::new(
+            self.replay::<serde_json::Value, ArchivedRow>("acled", old)?,
+            self.replay::<serde_json::Value, ArchivedRow>("acled", new)?,
+            |row| (&row.event.id, row.event.timestamp),
+        );
+
+        let added = delta
+            .added
+            .into_iter()
+            .map(|row| DeltaRecord::Added { event: row.row });
+        let updated = delta
+            .updated
+            .into_iter()
+            .map(|row| DeltaRecord::Updated { event: row.row });
+        let deleted = delta
+            .deleted
+            .into_iter()
+            .map(|event_id_cnty| DeltaRecord::Deleted { event_id_cnty });
+        for record in added.chain(updated).chain(deleted) {
+            serde_json::to_writer(&mut writer, &record)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn replay<D, E>(&self, endpoint: &str, manifest: &Manifest) -> Result<Vec<E>, Error>
+    where
+        D: DeserializeOwned,
+        E: TryFrom<D, Error = Error>,
+    {
+        if manifest.endpoint != endpoint {
+            return Err(Error::ArchiveError(format!(
+                "manifest is for the `{}` endpoint",
+                manifest.endpoint
+            )));
+        }
+
+        let mut all_events = Vec::new();
+        for (page, hash) in (1..).zip(&manifest.pages) {
+            let body = fs::read(self.page_path(hash)?)?;
+            if sha256_hex(&body) != *hash {
+                return Err(Error::ArchiveError(format!(
+                    "page {hash} does not match its hash"
+                )));
+            }
+            // Note: The limit doesn't matter, all pages are listed in the manifest.
+            all_events.extend(parse_page::<D, E>(&body, None, page)?.events);
+        }
+        if let Some(max_results) = manifest.max_results {
+            all_events.truncate(max_results);
+        }
+        Ok(all_events)
+    }
+}
+
+/// The difference between two archived pulls, see [`Archive::delta_acled`].
+#[derive(Clone, Debug)]
+pub struct Delta<T> {
+    /// Events that only exist in the newer pull.
+    pub added: Vec<T>,
+    /// Events that exist in both pulls, but were changed in the newer one.
+    pub updated: Vec<T>,
+    /// The ids of events that only exist in the older pull.
+    pub deleted: Vec<String>,
+}
+
+impl<T> Delta<T> {
+    /// Events are matched by the id returned by `key`, and count as updated
+    /// when the timestamp returned by it changed.
+    fn new(old: Vec<T>, new: Vec<T>, key: impl Fn(&T) -> (&String, u64)) -> Delta<T> {
+        let mut old_timestamps: HashMap<String, u64> = old
+            .iter()
+            .map(|event| {
+                let (id, timestamp) = key(event);
+                (id.clone(), timestamp)
+            })
+            .collect();
+
+        let mut delta = Delta {
+            added: Vec::new(),
+            updated: Vec::new(),
+            deleted: Vec::new(),
+        };
+        for event in new {
+            let (id, timestamp) = key(&event);
+            match old_timestamps.remove(id) {
+                None => delta.added.push(event),
+                Some(old_timestamp) if old_timestamp != timestamp => delta.updated.push(event),
+                Some(_) => {}
+            }
+        }
+        delta.deleted = old_timestamps.into_keys().collect();
+        delta.deleted.sort();
+        delta
+    }
+}
+
+/// A line written by [`Archive::export_delta`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "change", rename_all = "lowercase")]
+pub enum DeltaRecord {
+    /// An event that only exists in the newer pull, as returned by the API.
+    Added { event: serde_json::Value },
+    /// An event that was changed in the newer pull, as returned by the API.
+    Updated { event: serde_json::Value },
+    /// The id of an event that only exists in the older pull.
+    Deleted { event_id_cnty: String },
+}
+
+/// An archived row of the `acled` endpoint, parsed and as returned by the
+/// API, so [`Archive::export_delta`] can write it out unchanged.
+struct ArchivedRow {
+    event: AcledEvent,
+    row: serde_json::Value,
+}
+
+impl TryFrom<serde_json::Value> for ArchivedRow {
+    type Error = Error;
+
+    fn try_from(row: serde_json::Value) -> Result<ArchivedRow, Error> {
+        let data: AcledData = serde_json::from_value(row.clone())?;
+        Ok(ArchivedRow {
+            event: data.try_into()?,
+            row,
+        })
+    }
+}
+
+/// Describes a single archived pull: which query was executed, when and
+/// with which key, and the hashes of all pages that were returned (in order).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The endpoint that was queried, e.g. `acled`.
+    pub endpoint: String,
+    /// The query parameters, excluding the credentials.
+    pub parameters: Vec<(String, String)>,
+    /// The unix timestamp at which the pull was started.
+    pub fetched_at: u64,
+    /// A fingerprint of the API key that was used. This is a truncated hash,
+    /// so the key itself can't be recovered.
+    pub key_fingerprint: String,
+    /// The version of this crate that made the requests.
+    pub crate_version: String,
+    /// The maximum number of results of the pull, see e.g.
+    /// [`crate::AcledQuery::max_results`]. The last page can contain more
+    /// rows, which are dropped when replaying.
+    #[serde(default)]
+    pub max_results: Option<usize>,
+    /// The SHA-256 hashes of all pages, in order.
+    pub pages: Vec<String>,
+}
+
+impl Manifest {
+    /// Read a manifest from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Manifest, Error> {
+        let contents = fs::read(path)?;
+        Ok(serde_json::from_slice(&contents)?)
+    }
+
+    /// Write the manifest as a JSON file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+pub(crate) fn key_fingerprint(key: &str) -> String {
+    sha256_hex(key.as_bytes())[..16].to_owned()
+}
+
+pub(crate) fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::tests::MockTransport;
+
+    #[test]
+    fn store_page_test() {
+        let dir = std::env::temp_dir().join(format!("acled-archive-{}", std::process::id()));
+        let archive = Archive::new(&dir);
+
+        let hash = archive.store_page(b"{}").unwrap();
+        assert_eq!(
+            hash,
+            "44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a"
+        );
+        assert_eq!(fs::read(archive.page_path(&hash).unwrap()).unwrap(), b"{}");
+        assert_eq!(archive.store_page(b"{}").unwrap(), hash);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn replay_test() {
+        let dir = std::env::temp_dir().join(format!("acled-replay-{}", std::process::id()));
+        let archive = Archive::new(&dir);
+
+        let page = br#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI123","deleted_timestamp":"1710025200"}]}"#;
+        let mut manifest = Manifest {
+            endpoint: "deleted".into(),
+            parameters: Vec::new(),
+            fetched_at: 1710025300,
+            key_fingerprint: key_fingerprint("XXXXX"),
+            crate_version: env!("CARGO_PKG_VERSION").into(),
+            max_results: None,
+            pages: vec![archive.store_page(page).unwrap()],
+        };
+
+        let events = archive.replay_deleted(&manifest).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, "MLI123");
+        assert_eq!(events[0].timestamp, 1710025200);
+
+        assert!(matches!(
+            archive.replay_acled(&manifest),
+            Err(Error::ArchiveError(_))
+        ));
+
+        fs::write(archive.page_path(&manifest.pages[0]).unwrap(), b"{}").unwrap();
+        assert!(matches!(
+            archive.replay_deleted(&manifest),
+            Err(Error::ArchiveError(_))
+        ));
+
+        manifest.pages = vec!["0".repeat(64)];
+        assert!(matches!(
+            archive.replay_deleted(&manifest),
+            Err(Error::IoError(_))
+        ));
+
+        for hash in ["../../etc/passwd", &"A".repeat(64), &"0".repeat(63)] {
+            manifest.pages = vec![hash.to_owned()];
+            assert!(matches!(
+                archive.replay_deleted(&manifest),
+                Err(Error::ArchiveError(message)) if message.starts_with("invalid page hash")
+            ));
+        }
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    fn acled_page(events: &[(&str, u64)]) -> Vec<u8> {
+        let data: Vec<_> = events
+            .iter()
+            .map(|(id, timestamp)| crate::acled::tests::acled_row(id, *timestamp))
+            .collect();
+        serde_json::to_vec(&serde_json::json!({
+            "success": true,
+            "count": data.len(),
+            "data": data,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn replay_max_results_test() {
+        let dir = std::env::temp_dir().join(format!("acled-capped-{}", std::process::id()));
+        let archive = Archive::new(&dir);
+
+        let page = acled_page(&[("MLI1", 100), ("MLI2", 100), ("MLI3", 100)]);
+        let api = crate::Api::builder()
+            .configuration(crate::Configuration {
+                key: "XXXXX".into(),
+                email: "foo@example.com".into(),
+            })
+            .transport(MockTransport::new(vec![(
+                200,
+                String::from_utf8(page).unwrap(),
+            )]))
+            .build()
+            .unwrap();
+        let query = crate::AcledQuery {
+            max_results: Some(2),
+            ..Default::default()
+        };
+        let (events, manifest) = api.get_acled_archived(&query, &archive).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(manifest.max_results, Some(2));
+        assert_eq!(archive.replay_acled(&manifest).unwrap(), events);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn delta_test() {
+        let dir = std::env::temp_dir().join(format!("acled-delta-{}", std::process::id()));
+        let archive = Archive::new(&dir);
+
+        let manifest = |page: &[u8]| Manifest {
+            endpoint: "acled".into(),
+            parameters: Vec::new(),
+            fetched_at: 1710025300,
+            key_fingerprint: key_fingerprint("XXXXX"),
+            crate_version: env!("CARGO_PKG_VERSION").into(),
+            max_results: None,
+            pages: vec![archive.store_page(page).unwrap()],
+        };
+        let old = manifest(&acled_page(&[("MLI1", 100), ("MLI2", 100), ("MLI3", 100)]));
+        let new = manifest(&acled_page(&[("MLI1", 100), ("MLI2", 200), ("MLI4", 200)]));
+
+        let delta = archive.delta_acled(&old, &new).unwrap();
+        let ids = |events: &[AcledEvent]| events.iter().map(|e| e.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&delta.added), vec!["MLI4"]);
+        assert_eq!(delta.added[0].extras["new_column"], "new");
+        assert_eq!(ids(&delta.updated), vec!["MLI2"]);
+        assert_eq!(delta.deleted, vec!["MLI3"]);
+
+        let mut output = Vec::new();
+        archive.export_delta(&old, &new, &mut output).unwrap();
+        let records: Vec<DeltaRecord> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let row = crate::acled::tests::acled_row;
+        assert_eq!(
+            records,
+            vec![
+                DeltaRecord::Added {
+                    event: row("MLI4", 200)
+                },
+                DeltaRecord::Updated {
+                    event: row("MLI2", 200)
+                },
+                DeltaRecord::Deleted {
+                    event_id_cnty: "MLI3".into()
+                },
+            ]
+        );
+
+        // The exported rows parse like rows returned by the API.
+        let DeltaRecord::Added { event } = &records[0] else {
+            unreachable!()
+        };
+        let event: AcledEvent = serde_json::from_value::<AcledData>(event.clone())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(event, delta.added[0]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
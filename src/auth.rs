@@ -0,0 +1,308 @@
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+use crate::{Configuration, Error};
+use serde::Deserialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// The base URL of the legacy API, which uses [`Credentials::Key`].
+pub(crate) const LEGACY_BASE: &str = "https://api.acleddata.com";
+/// The base URL of the API that uses [`Credentials::OAuth`].
+pub(crate) const OAUTH_BASE: &str = "https://acleddata.com/api";
+const CLIENT_ID: &str = "acled";
+
+/// How requests to the API are authenticated. See
+/// [`crate::Api::with_credentials`].
+pub enum Credentials {
+    /// The legacy `key` and `email` query parameters.
+    Key(Configuration),
+    /// Access tokens for the new API, obtained with the email address and
+    /// password of an ACLED account. Tokens are requested on first use,
+    /// refreshed when they expire, and requested again when the API rejects
+    /// them (HTTP 401). Tokens come from the `/oauth/token` endpoint next to
+    /// the base URL in use, see [`crate::Api::with_base_url`].
+    OAuth { username: String, password: String },
+}
+
+impl Credentials {
+    /// The base URL of the API that accepts these credentials.
+    pub(crate) fn base(&self) -> &'static str {
+        match self {
+            Credentials::Key(_) => LEGACY_BASE,
+            Credentials::OAuth { .. } => OAUTH_BASE,
+        }
+    }
+
+    /// A value identifying the account, without revealing the secret.
+    pub(crate) fn account(&self) -> &str {
+        match self {
+            Credentials::Key(config) => &config.key,
+            Credentials::OAuth { username, .. } => username,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: String,
+    expires_in: u64,
+}
+
+struct Token {
+    access: String,
+    refresh: String,
+    expires_at: Instant,
+}
+
+/// Authenticates requests with [`Credentials`], caching OAuth tokens.
+pub(crate) struct Auth {
+    credentials: Credentials,
+    token: Mutex<Option<Token>>,
+}
+
+impl Auth {
+    pub(crate) fn new(credentials: Credentials) -> Auth {
+        Auth {
+            credentials,
+            token: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+
+    /// The parameters that have to be added to the query string.
+    pub(crate) fn parameters(&self) -> Vec<(String, String)> {
+        match &self.credentials {
+            Credentials::Key(config) => vec![
+                ("key".into(), config.key.clone()),
+                ("email".into(), config.email.clone()),
+            ],
+            Credentials::OAuth { .. } => vec![("_format".into(), "json".into())],
+        }
+    }
+
+    /// Send `request` to `base`, authenticating it if necessary. With OAuth,
+    /// a rejected token is replaced and the request is sent once more.
+    pub(crate) fn send(
+        &self,
+        transport: &dyn HttpTransport,
+        base: &str,
+        request: HttpRequest,
+    ) -> Result<HttpResponse, Error> {
+        let Credentials::OAuth { .. } = &self.credentials else {
+            return transport.send(request);
+        };
+
+        let bearer = |request: &HttpRequest, token: String| {
+            let mut request = request.clone();
+            request
+                .headers
+                .push(("Authorization".into(), format!("Bearer {token}")));
+            request
+        };
+
+        let response = transport.send(bearer(&request, self.access_token(transport, base)?))?;
+        if response.status != 401 {
+            return Ok(response);
+        }
+
+        *self.token.lock().unwrap() = None;
+        transport.send(bearer(&request, self.access_token(transport, base)?))
+    }
+
+    /// A valid access token, requesting or refreshing it if necessary.
+    fn access_token(&self, transport: &dyn HttpTransport, base: &str) -> Result<String, Error> {
+        let Credentials::OAuth { username, password } = &self.credentials else {
+            unreachable!("only OAuth credentials use tokens");
+        };
+
+        let mut token = self.token.lock().unwrap();
+        // Note: Refresh a bit early, so the token doesn't expire in the
+        // middle of a request.
+        let margin = Duration::from_secs(60);
+        match token.as_ref() {
+            Some(current) if current.expires_at > Instant::now() + margin => {
+                return Ok(current.access.clone());
+            }
+            Some(current) => {
+                let refresh = current.refresh.clone();
+                *token = request_token(
+                    transport,
+                    base,
+                    &[
+                        ("grant_type", "refresh_token"),
+                        ("refresh_token", &refresh),
+                        ("client_id", CLIENT_ID),
+                    ],
+                )
+                .ok();
+            }
+            None => {}
+        }
+
+        if token.is_none() {
+            *token = Some(request_token(
+                transport,
+                base,
+                &[
+                    ("grant_type", "password"),
+                    ("username", username),
+                    ("password", password),
+                    ("client_id", CLIENT_ID),
+                    ("scope", "authenticated"),
+                ],
+            )?);
+        }
+        Ok(token.as_ref().unwrap().access.clone())
+    }
+}
+
+/// The token endpoint that belongs to `base`, so credentials are only ever
+/// sent to the configured server, e.g. `https://acleddata.com/oauth/token`
+/// for `https://acleddata.com/api`, or `http://localhost:8080/oauth/token`
+/// for a proxy at `http://localhost:8080`.
+pub(crate) fn token_url(base: &str) -> Result<Url, Error> {
+    let base = base.trim_end_matches('/');
+    let root = base.strip_suffix("/api").unwrap_or(base);
+    let url = format!("{root}/oauth/token");
+    Url::parse(&url)
+        .map_err(|error| Error::InvalidConfiguration(format!("invalid URL `{url}`: {error}")))
+}
+
+fn request_token(
+    transport: &dyn HttpTransport,
+    base: &str,
+    form: &[(&str, &str)],
+) -> Result<Token, Error> {
+    let requested_at = Instant::now();
+    let response = transport.send(HttpRequest::post_form(token_url(base)?, form))?;
+    if !(200..300).contains(&response.status) {
+        return Err(Error::HttpStatus(response.status));
+    }
+    let response: TokenResponse = response.json()?;
+    Ok(Token {
+        access: response.access_token,
+        refresh: response.refresh_token,
+        expires_at: requested_at + Duration::from_secs(response.expires_in),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::tests::MockTransport;
+    use std::io::Read;
+
+    #[test]
+    fn parameters_test() {
+        let auth = Auth::new(Credentials::Key(Configuration {
+            key: "XXXXX".into(),
+            email: "foo@example.com".into(),
+        }));
+        assert_eq!(
+            auth.parameters(),
+            vec![
+                ("key".into(), "XXXXX".into()),
+                ("email".into(), "foo@example.com".into())
+            ]
+        );
+        assert_eq!(auth.credentials().base(), LEGACY_BASE);
+
+        let auth = Auth::new(Credentials::OAuth {
+            username: "foo@example.com".into(),
+            password: "secret".into(),
+        });
+        assert_eq!(auth.parameters(), vec![("_format".into(), "json".into())]);
+        assert_eq!(auth.credentials().account(), "foo@example.com");
+    }
+
+    #[test]
+    fn reauthenticate_test() {
+        let token = r#"{"access_token":"first","refresh_token":"refresh","expires_in":86400}"#;
+        let second = r#"{"access_token":"second","refresh_token":"refresh","expires_in":86400}"#;
+        let transport = MockTransport::new(vec![
+            (200, token),
+            (200, "page 1"),
+            (401, "expired"),
+            (200, second),
+            (200, "page 2"),
+        ]);
+        let auth = Auth::new(Credentials::OAuth {
+            username: "foo@example.com".into(),
+            password: "secret".into(),
+        });
+        let request = |page| {
+            HttpRequest::get(
+                Url::parse(&format!("{OAUTH_BASE}/acled/read?page={page}")).unwrap(),
+                Vec::new(),
+            )
+        };
+
+        for page in [1, 2] {
+            let mut response = auth.send(&transport, OAUTH_BASE, request(page)).unwrap();
+            let mut body = String::new();
+            response.body.read_to_string(&mut body).unwrap();
+            assert_eq!(body, format!("page {page}"));
+        }
+
+        let requests = transport.requests.lock().unwrap();
+        let authorization: Vec<_> = requests
+            .iter()
+            .map(|request| request.header("authorization"))
+            .collect();
+        assert_eq!(
+            authorization,
+            vec![
+                None,
+                Some("Bearer first"),
+                Some("Bearer first"),
+                None,
+                Some("Bearer second")
+            ]
+        );
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://acleddata.com/oauth/token"
+        );
+    }
+
+    #[test]
+    fn token_url_test() {
+        let token_url = |base| token_url(base).unwrap().to_string();
+        assert_eq!(token_url(OAUTH_BASE), "https://acleddata.com/oauth/token");
+        assert_eq!(
+            token_url("http://localhost:8080/"),
+            "http://localhost:8080/oauth/token"
+        );
+        assert_eq!(
+            token_url("https://proxy.internal/acled/api"),
+            "https://proxy.internal/acled/oauth/token"
+        );
+        assert!(matches!(
+            super::token_url("not a url"),
+            Err(Error::InvalidConfiguration(_))
+        ));
+
+        let token = r#"{"access_token":"first","refresh_token":"refresh","expires_in":86400}"#;
+        let transport = MockTransport::new(vec![(200, token), (200, "page 1")]);
+        let auth = Auth::new(Credentials::OAuth {
+            username: "foo@example.com".into(),
+            password: "secret".into(),
+        });
+        let base = "http://localhost:8080";
+        let request = HttpRequest::get(
+            Url::parse(&format!("{base}/acled/read")).unwrap(),
+            Vec::new(),
+        );
+        auth.send(&transport, base, request).unwrap();
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "http://localhost:8080/oauth/token"
+        );
+    }
+}
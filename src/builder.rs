@@ -0,0 +1,360 @@
+use crate::disorder_type::DisorderType;
+use crate::event_type::{EventType, SubEventType};
+use crate::field::Field;
+use crate::inter::{Inter, Interaction};
+use crate::precision::{GeoPrecision, TimePrecision};
+use crate::region::Region;
+use crate::{
+    AcledQuery, ActorQuery, ActorTypeQuery, CastQuery, CountryQuery, DeletedQuery, RegionQuery,
+    Where,
+};
+use chrono::{Month, NaiveDate};
+
+/// Generates a builder with setters for every `Where` field of a query,
+/// e.g. `country(..)`, `country_eq(..)`, `country_between(.., ..)`, and
+/// for the pagination options. `$arg` is the argument type of the setters,
+/// which allows passing `&str` for `String` fields.
+///
+/// Every field starts with its kind, which decides the comparisons it gets
+/// setters for: `text` fields also get `_like` and `_not_like`, `ordered`
+/// fields also get `_gt`, `_gte` and `_between`, and `plain` fields only get
+/// the (in)equality setters.
+macro_rules! query_builder {
+    (
+        $(#[$attr:meta])*
+        $builder:ident for $query:ident {
+            $($kind:ident $field:ident: $type:ty => $arg:ty,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Default)]
+        pub struct $builder {
+            query: $query,
+        }
+
+        impl $query {
+            #[doc = concat!("Start building a query. See [`", stringify!($builder), "`].")]
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl $builder {
+            /// Finish building the query.
+            pub fn build(self) -> $query {
+                self.query
+            }
+
+            /// See the `limit` field of the query.
+            pub fn limit(mut self, limit: u32) -> Self {
+                self.query.limit = Some(limit);
+                self
+            }
+
+            /// See the `max_results` field of the query.
+            pub fn max_results(mut self, max_results: usize) -> Self {
+                self.query.max_results = Some(max_results);
+                self
+            }
+
+            /// See the `max_pages` field of the query.
+            pub fn max_pages(mut self, max_pages: u32) -> Self {
+                self.query.max_pages = Some(max_pages);
+                self
+            }
+
+            $(
+                query_builder_setters!(common $field: $type => $arg);
+                query_builder_setters!($kind $field: $type => $arg);
+            )*
+        }
+    };
+}
+
+/// The setters of a single field, see `query_builder!`.
+macro_rules! query_builder_setters {
+    (common $field:ident: $type:ty => $arg:ty) => {
+        paste::paste! {
+            /// Set the filter for this field.
+            pub fn $field(mut self, filter: Where<$type>) -> Self {
+                self.query.$field = filter;
+                self
+            }
+
+            /// See [`Where::Matches`].
+            pub fn [<$field _matches>](self, value: $arg) -> Self {
+                self.$field(Where::Matches(value.into()))
+            }
+
+            /// See [`Where::Equal`].
+            pub fn [<$field _eq>](self, value: $arg) -> Self {
+                self.$field(Where::Equal(value.into()))
+            }
+
+            /// See [`Where::NotEqual`].
+            pub fn [<$field _ne>](self, value: $arg) -> Self {
+                self.$field(Where::NotEqual(value.into()))
+            }
+
+            /// See [`Where::In`].
+            pub fn [<$field _in>](
+                self,
+                values: impl IntoIterator<Item = $arg>,
+            ) -> Self {
+                self.$field(Where::In(values.into_iter().map(Into::into).collect()))
+            }
+        }
+    };
+    (plain $field:ident: $type:ty => $arg:ty) => {};
+    (text $field:ident: $type:ty => $arg:ty) => {
+        paste::paste! {
+            /// See [`Where::Like`].
+            pub fn [<$field _like>](self, value: $arg) -> Self {
+                self.$field(Where::Like(value.into()))
+            }
+
+            /// See [`Where::NotLike`].
+            pub fn [<$field _not_like>](self, value: $arg) -> Self {
+                self.$field(Where::NotLike(value.into()))
+            }
+        }
+    };
+    (ordered $field:ident: $type:ty => $arg:ty) => {
+        paste::paste! {
+            /// See [`Where::GreaterThan`].
+            pub fn [<$field _gt>](self, value: $arg) -> Self {
+                self.$field(Where::GreaterThan(value.into()))
+            }
+
+            /// See [`Where::GreaterThanOrEqual`].
+            pub fn [<$field _gte>](self, value: $arg) -> Self {
+                self.$field(Where::GreaterThanOrEqual(value.into()))
+            }
+
+            /// See [`Where::Between`].
+            pub fn [<$field _between>](
+                self,
+                start: $arg,
+                end: $arg,
+            ) -> Self {
+                self.$field(Where::Between(start.into(), end.into()))
+            }
+        }
+    };
+}
+
+query_builder! {
+    /// A builder for [`AcledQuery`].
+    ///
+    /// ```
+    /// use acled_api::{AcledQuery, NaiveDate};
+    ///
+    /// let query = AcledQuery::builder()
+    ///     .country_eq("Ukraine")
+    ///     .date_between(
+    ///         NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+    ///     )
+    ///     .fatalities_gte(1)
+    ///     .build();
+    /// ```
+    AcledQueryBuilder for AcledQuery {
+        text country: String => impl Into<String>,
+        text id: String => impl Into<String>,
+        ordered year: u32 => u32,
+        plain region: Region => Region,
+        ordered date: NaiveDate => NaiveDate,
+        ordered timestamp: u64 => u64,
+        text actor1: String => impl Into<String>,
+        text actor2: String => impl Into<String>,
+        text assoc_actor_1: String => impl Into<String>,
+        text assoc_actor_2: String => impl Into<String>,
+        ordered fatalities: u32 => u32,
+        plain event_type: EventType => EventType,
+        plain sub_event_type: SubEventType => SubEventType,
+        plain disorder_type: DisorderType => DisorderType,
+        plain iso: u32 => u32,
+        text admin1: String => impl Into<String>,
+        text admin2: String => impl Into<String>,
+        text admin3: String => impl Into<String>,
+        text location: String => impl Into<String>,
+        ordered latitude: f64 => f64,
+        ordered longitude: f64 => f64,
+        plain inter1: Inter => Inter,
+        plain inter2: Inter => Inter,
+        plain interaction: Interaction => Interaction,
+        text source: String => impl Into<String>,
+        text source_scale: String => impl Into<String>,
+        text tags: String => impl Into<String>,
+        plain geo_precision: GeoPrecision => GeoPrecision,
+        plain time_precision: TimePrecision => TimePrecision,
+    }
+}
+
+query_builder! {
+    /// A builder for [`DeletedQuery`].
+    ///
+    /// ```
+    /// use acled_api::DeletedQuery;
+    ///
+    /// let query = DeletedQuery::builder()
+    ///     .timestamp_gte(1710025200)
+    ///     .build();
+    /// ```
+    DeletedQueryBuilder for DeletedQuery {
+        text id: String => impl Into<String>,
+        ordered timestamp: u64 => u64,
+        ordered date: NaiveDate => NaiveDate,
+        text country: String => impl Into<String>,
+    }
+}
+
+query_builder! {
+    /// A builder for [`ActorQuery`].
+    ///
+    /// ```
+    /// use acled_api::ActorQuery;
+    ///
+    /// let query = ActorQuery::builder()
+    ///     .name_like("Wagner Group*")
+    ///     .event_count_gte(100)
+    ///     .build();
+    /// ```
+    ActorQueryBuilder for ActorQuery {
+        text name: String => impl Into<String>,
+        ordered first_event_date: NaiveDate => NaiveDate,
+        ordered last_event_date: NaiveDate => NaiveDate,
+        ordered event_count: u32 => u32,
+    }
+}
+
+query_builder! {
+    /// A builder for [`ActorTypeQuery`].
+    ///
+    /// ```
+    /// use acled_api::ActorTypeQuery;
+    ///
+    /// let query = ActorTypeQuery::builder().name_like("*militias").build();
+    /// ```
+    ActorTypeQueryBuilder for ActorTypeQuery {
+        plain id: u32 => u32,
+        text name: String => impl Into<String>,
+        ordered first_event_date: NaiveDate => NaiveDate,
+        ordered last_event_date: NaiveDate => NaiveDate,
+        ordered event_count: u32 => u32,
+    }
+}
+
+query_builder! {
+    /// A builder for [`CountryQuery`].
+    ///
+    /// ```
+    /// use acled_api::CountryQuery;
+    ///
+    /// let query = CountryQuery::builder().event_count_gte(1000).build();
+    /// ```
+    CountryQueryBuilder for CountryQuery {
+        text name: String => impl Into<String>,
+        plain iso: u32 => u32,
+        ordered first_event_date: NaiveDate => NaiveDate,
+        ordered last_event_date: NaiveDate => NaiveDate,
+        ordered event_count: u32 => u32,
+    }
+}
+
+query_builder! {
+    /// A builder for [`RegionQuery`].
+    ///
+    /// ```
+    /// use acled_api::RegionQuery;
+    ///
+    /// let query = RegionQuery::builder().event_count_gte(1000).build();
+    /// ```
+    RegionQueryBuilder for RegionQuery {
+        plain region: Region => Region,
+        text name: String => impl Into<String>,
+        ordered first_event_date: NaiveDate => NaiveDate,
+        ordered last_event_date: NaiveDate => NaiveDate,
+        ordered event_count: u32 => u32,
+    }
+}
+
+query_builder! {
+    /// A builder for [`CastQuery`].
+    ///
+    /// ```
+    /// use acled_api::{CastQuery, Month};
+    ///
+    /// let query = CastQuery::builder()
+    ///     .country_matches("Mali")
+    ///     .month_matches(Month::March)
+    ///     .year_gte(2024)
+    ///     .build();
+    /// ```
+    CastQueryBuilder for CastQuery {
+        text country: String => impl Into<String>,
+        text admin1: String => impl Into<String>,
+        plain month: Month => Month,
+        ordered year: u32 => u32,
+        ordered timestamp: u64 => u64,
+    }
+}
+
+impl AcledQueryBuilder {
+    /// See [`AcledQuery::fields`].
+    pub fn fields(mut self, fields: impl IntoIterator<Item = Field>) -> Self {
+        self.query.fields = Some(fields.into_iter().collect());
+        self
+    }
+
+    /// See [`AcledQuery::population`].
+    pub fn population(mut self, population: bool) -> Self {
+        self.query.population = population;
+        self
+    }
+
+    /// See [`AcledQuery::civilian_targeting`].
+    pub fn civilian_targeting(mut self, civilian_targeting: bool) -> Self {
+        self.query.civilian_targeting = Some(civilian_targeting);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_test() {
+        let query = AcledQuery::builder()
+            .country_in(["Mali", "Niger"])
+            .event_type_ne(EventType::Protests)
+            .fatalities_gte(1)
+            .civilian_targeting(true)
+            .limit(100)
+            .max_pages(2)
+            .build();
+        assert_eq!(
+            query.as_parameters(),
+            AcledQuery {
+                country: Where::In(vec!["Mali".into(), "Niger".into()]),
+                event_type: Where::NotEqual(EventType::Protests),
+                fatalities: Where::GreaterThanOrEqual(1),
+                civilian_targeting: Some(true),
+                limit: Some(100),
+                ..Default::default()
+            }
+            .as_parameters()
+        );
+        assert_eq!(query.max_pages, Some(2));
+
+        let query = DeletedQuery::builder().id_eq("MLI123").build();
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("event_id_cnty_where".into(), "=".into()),
+                ("event_id_cnty".into(), "MLI123".into())
+            ]
+        );
+    }
+}
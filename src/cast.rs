@@ -0,0 +1,213 @@
+use crate::{page::Paging, response::CastData, sealed, AsParameter, Error, Where};
+use chrono::Month;
+
+/// This struct is used for specifying the query parameters for the `cast`
+/// endpoint, which returns the forecasts of ACLED's Conflict Alert System.
+/// See <https://apidocs.acleddata.com/cast_endpoint.html>.
+///
+/// All fields are optional and can be default initialized.
+///
+/// ```
+/// use acled_api::{CastQuery, Where};
+///
+/// let query = CastQuery {
+///   country: Where::Matches("Mali".into()),
+///   year: Where::Matches(2024),
+///   ..Default::default()
+/// };
+/// ```
+#[derive(Default)]
+pub struct CastQuery {
+    pub country: Where<String>,
+    pub admin1: Where<String>,
+    pub month: Where<Month>,
+    pub year: Where<u32>,
+    pub timestamp: Where<u64>,
+    /// The maximum number of rows returned per page. Defaults to the
+    /// API's own limit of 5000 rows when unset.
+    pub limit: Option<u32>,
+    /// Stop fetching once at least this many forecasts have been received.
+    /// Any surplus forecasts are discarded. This is not sent to the API.
+    pub max_results: Option<usize>,
+    /// Stop fetching after this many pages. This is not sent to the API.
+    pub max_pages: Option<u32>,
+}
+
+impl CastQuery {
+    pub(crate) fn paging(&self) -> Paging {
+        Paging {
+            limit: self.limit,
+            max_results: self.max_results,
+            max_pages: self.max_pages,
+        }
+    }
+
+    /// The query parameters that are sent to the API, sorted by name and
+    /// value. This doesn't include the credentials or the `page` parameter.
+    pub fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = self.as_parameters();
+        pairs.sort();
+        pairs
+    }
+
+    pub(crate) fn as_parameters(&self) -> Vec<(String, String)> {
+        let CastQuery {
+            country,
+            admin1,
+            month,
+            year,
+            timestamp,
+            limit,
+            max_results: _,
+            max_pages: _,
+        } = self;
+
+        let mut parameters = Vec::new();
+        parameters.extend_from_slice(&country.as_parameters("country"));
+        parameters.extend_from_slice(&admin1.as_parameters("admin1"));
+        parameters.extend_from_slice(&month.as_parameters("month"));
+        parameters.extend_from_slice(&year.as_parameters("year"));
+        parameters.extend_from_slice(&timestamp.as_parameters("timestamp"));
+        if let Some(limit) = limit {
+            parameters.push(("limit".into(), limit.to_string()));
+        }
+        parameters
+    }
+}
+
+impl AsParameter for Month {
+    fn as_parameter(&self) -> String {
+        // Note: The API uses the full name of the month, e.g. `January`.
+        self.name().to_owned()
+    }
+}
+
+impl sealed::Sealed for Month {}
+
+/// A country-month (or first-level administrative region) forecast returned
+/// by the `cast` endpoint.
+///
+/// Descriptions based on <https://apidocs.acleddata.com/cast_endpoint.html>
+#[derive(Clone, Debug)]
+pub struct CastForecast {
+    /// The country or territory of the forecast.
+    pub country: String,
+    /// The sub-national administrative region of the forecast.
+    pub admin1: String,
+    /// The month of the forecast.
+    pub month: Month,
+    /// The year of the forecast.
+    pub year: u32,
+    /// The forecasted number of political violence events.
+    ///
+    /// Renamed from `total_forecast`.
+    pub total: f64,
+    /// The forecasted number of battles.
+    ///
+    /// Renamed from `battles_forecast`.
+    pub battles: f64,
+    /// The forecasted number of explosions/remote violence events.
+    ///
+    /// Renamed from `erv_forecast`.
+    pub explosions_remote_violence: f64,
+    /// The forecasted number of violence against civilians events.
+    ///
+    /// Renamed from `vac_forecast`.
+    pub violence_against_civilians: f64,
+    /// The number of political violence events that were actually recorded,
+    /// once the month is over.
+    ///
+    /// Renamed from `total_observed`.
+    pub total_observed: Option<u32>,
+    /// Renamed from `battles_observed`.
+    pub battles_observed: Option<u32>,
+    /// Renamed from `erv_observed`.
+    pub explosions_remote_violence_observed: Option<u32>,
+    /// Renamed from `vac_observed`.
+    pub violence_against_civilians_observed: Option<u32>,
+    /// An automatically generated Unix timestamp that represents the exact date
+    /// and time the forecast was last uploaded to the ACLED API.
+    pub timestamp: u64,
+}
+
+impl TryFrom<CastData> for CastForecast {
+    type Error = Error;
+
+    fn try_from(data: CastData) -> Result<Self, Self::Error> {
+        fn forecast(value: &str, column: &str) -> Result<f64, Error> {
+            value.parse().map_err(|_| Error::ParseError(column.into()))
+        }
+
+        fn observed(value: Option<String>, column: &str) -> Result<Option<u32>, Error> {
+            value
+                .filter(|value| !value.is_empty())
+                .map(|value| value.parse())
+                .transpose()
+                .map_err(|_| Error::ParseError(column.into()))
+        }
+
+        Ok(CastForecast {
+            country: data.country,
+            admin1: data.admin1,
+            month: data
+                .month
+                .parse()
+                .map_err(|_| Error::ParseError("month".into()))?,
+            year: data
+                .year
+                .parse()
+                .map_err(|_| Error::ParseError("year".into()))?,
+            total: forecast(&data.total_forecast, "total_forecast")?,
+            battles: forecast(&data.battles_forecast, "battles_forecast")?,
+            explosions_remote_violence: forecast(&data.erv_forecast, "erv_forecast")?,
+            violence_against_civilians: forecast(&data.vac_forecast, "vac_forecast")?,
+            total_observed: observed(data.total_observed, "total_observed")?,
+            battles_observed: observed(data.battles_observed, "battles_observed")?,
+            explosions_remote_violence_observed: observed(data.erv_observed, "erv_observed")?,
+            violence_against_civilians_observed: observed(data.vac_observed, "vac_observed")?,
+            timestamp: data
+                .timestamp
+                .parse()
+                .map_err(|_| Error::ParseError("timestamp".into()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    #[test]
+    fn query_test() {
+        let query = CastQuery {
+            country: Where::Matches("Mali".into()),
+            month: Where::Matches(Month::March),
+            year: Where::Matches(2024),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("country".into(), "Mali".into()),
+                ("month".into(), "March".into()),
+                ("year".into(), "2024".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn forecast_test() {
+        let body = br#"{"success":true,"count":1,"data":[{"country":"Mali","admin1":"Mopti","month":"March","year":"2024","total_forecast":"42","battles_forecast":"20","erv_forecast":"7","vac_forecast":"15","total_observed":"","battles_observed":null,"timestamp":"1709251200"}]}"#;
+        let forecasts = serde_json::from_slice::<Response<CastData>>(body)
+            .unwrap()
+            .into::<CastForecast>()
+            .unwrap();
+        assert_eq!(forecasts[0].admin1, "Mopti");
+        assert_eq!(forecasts[0].month, Month::March);
+        assert_eq!(forecasts[0].year, 2024);
+        assert_eq!(forecasts[0].total, 42.0);
+        assert_eq!(forecasts[0].total_observed, None);
+        assert_eq!(forecasts[0].battles_observed, None);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::AcledEvent;
+use chrono::NaiveDate;
+
+/// The attribution ACLED requires when its data is used or published.
+/// <https://acleddata.com/terms-of-use/>
+pub const ATTRIBUTION: &str =
+    "Armed Conflict Location & Event Data Project (ACLED); www.acleddata.com";
+
+/// The ACLED dashboard, to link to the data online. ACLED doesn't offer
+/// links to individual events.
+pub const DASHBOARD_URL: &str = "https://acleddata.com/dashboard/#/dashboard";
+
+impl AcledEvent {
+    /// A citation of this event, including the date the data was accessed.
+    ///
+    /// ```
+    /// # use acled_api::{AcledEvent, NaiveDate};
+    /// # fn example(event: &AcledEvent) {
+    /// let citation = event.citation(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap());
+    /// // ACLED event MLI123 (Mali, 2024-03-01). Armed Conflict Location & Event
+    /// // Data Project (ACLED); www.acleddata.com. Accessed 2024-03-10.
+    /// # }
+    /// ```
+    pub fn citation(&self, accessed: NaiveDate) -> String {
+        format!(
+            "ACLED event {} ({}, {}). {ATTRIBUTION}. Accessed {accessed}.",
+            self.id, self.country, self.date
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acled::tests::acled_event;
+
+    #[test]
+    fn citation_test() {
+        let event = acled_event("MLI123");
+        assert_eq!(
+            event.citation(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap()),
+            "ACLED event MLI123 (Mali, 2024-03-01). Armed Conflict Location & Event Data Project (ACLED); www.acleddata.com. Accessed 2024-03-10."
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{page::Paging, region::Region, response::CountryData, Error, Where};
+use chrono::NaiveDate;
+
+/// This struct is used for specifying the query parameters for the `country`
+/// endpoint. See <https://apidocs.acleddata.com/country_endpoint.html#query-filters>.
+///
+/// All fields are optional and can be default initialized.
+///
+/// ```
+/// use acled_api::{CountryQuery, Where};
+///
+/// let query = CountryQuery {
+///   event_count: Where::GreaterThanOrEqual(1000),
+///   ..Default::default()
+/// };
+/// ```
+#[derive(Default)]
+pub struct CountryQuery {
+    /// Sent as `country`.
+    pub name: Where<String>,
+    /// The ISO 3166-1 numeric code, see [`crate::AcledQuery::iso_from_alpha`].
+    pub iso: Where<u32>,
+    pub first_event_date: Where<NaiveDate>,
+    pub last_event_date: Where<NaiveDate>,
+    pub event_count: Where<u32>,
+    /// The maximum number of rows returned per page. Defaults to the
+    /// API's own limit of 5000 rows when unset.
+    pub limit: Option<u32>,
+    /// Stop fetching once at least this many countries have been received.
+    /// Any surplus countries are discarded. This is not sent to the API.
+    pub max_results: Option<usize>,
+    /// Stop fetching after this many pages. This is not sent to the API.
+    pub max_pages: Option<u32>,
+}
+
+impl CountryQuery {
+    pub(crate) fn paging(&self) -> Paging {
+        Paging {
+            limit: self.limit,
+            max_results: self.max_results,
+            max_pages: self.max_pages,
+        }
+    }
+
+    /// The query parameters that are sent to the API, sorted by name and
+    /// value. This doesn't include the credentials or the `page` parameter.
+    pub fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = self.as_parameters();
+        pairs.sort();
+        pairs
+    }
+
+    pub(crate) fn as_parameters(&self) -> Vec<(String, String)> {
+        let CountryQuery {
+            name,
+            iso,
+            first_event_date,
+            last_event_date,
+            event_count,
+            limit,
+            max_results: _,
+            max_pages: _,
+        } = self;
+
+        let mut parameters = Vec::new();
+        parameters.extend_from_slice(&name.as_parameters("country"));
+        parameters.extend_from_slice(&iso.as_parameters("iso"));
+        parameters.extend_from_slice(&first_event_date.as_parameters("first_event_date"));
+        parameters.extend_from_slice(&last_event_date.as_parameters("last_event_date"));
+        parameters.extend_from_slice(&event_count.as_parameters("event_count"));
+        if let Some(limit) = limit {
+            parameters.push(("limit".into(), limit.to_string()));
+        }
+        parameters
+    }
+}
+
+/// A country returned by the `country` endpoint.
+///
+/// Descriptions based on <https://apidocs.acleddata.com/country_endpoint.html>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Country {
+    /// The name of the country, as used in the `country` column.
+    ///
+    /// Renamed from `country`.
+    pub name: String,
+    /// The ISO 3166-1 numeric code of the country.
+    pub iso: u32,
+    /// The ISO 3166-1 alpha-3 code of the country.
+    pub iso3: String,
+    /// The date of the earliest event in the country.
+    pub first_event_date: NaiveDate,
+    /// The date of the latest event in the country.
+    pub last_event_date: NaiveDate,
+    /// The number of events in the country.
+    pub event_count: u32,
+}
+
+impl Country {
+    /// The region of the country, see [`Region::of_country`].
+    pub fn region(&self) -> Option<Region> {
+        Region::of_country(&self.name)
+    }
+}
+
+impl TryFrom<CountryData> for Country {
+    type Error = Error;
+
+    fn try_from(data: CountryData) -> Result<Self, Self::Error> {
+        Ok(Country {
+            name: data.country,
+            iso: data
+                .iso
+                .parse()
+                .map_err(|_| Error::ParseError("iso".into()))?,
+            iso3: data.iso3,
+            first_event_date: NaiveDate::parse_from_str(&data.first_event_date, "%Y-%m-%d")
+                .map_err(|_| Error::ParseError("first_event_date".into()))?,
+            last_event_date: NaiveDate::parse_from_str(&data.last_event_date, "%Y-%m-%d")
+                .map_err(|_| Error::ParseError("last_event_date".into()))?,
+            event_count: data
+                .event_count
+                .parse()
+                .map_err(|_| Error::ParseError("event_count".into()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    #[test]
+    fn query_test() {
+        let query = CountryQuery {
+            name: Where::Matches("Mali".into()),
+            event_count: Where::GreaterThanOrEqual(1000),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("country".into(), "Mali".into()),
+                ("event_count_where".into(), ">=".into()),
+                ("event_count".into(), "1000".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn country_test() {
+        let body = br#"{"success":true,"count":1,"data":[{"country":"Mali","iso":"466","iso3":"MLI","first_event_date":"1997-01-04","last_event_date":"2024-03-01","event_count":"12345"}]}"#;
+        let countries = serde_json::from_slice::<Response<CountryData>>(body)
+            .unwrap()
+            .into::<Country>()
+            .unwrap();
+        assert_eq!(countries[0].iso, 466);
+        assert_eq!(countries[0].iso3, "MLI");
+        assert_eq!(countries[0].region(), Some(Region::WesternAfrica));
+    }
+}
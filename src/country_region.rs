@@ -0,0 +1,314 @@
+use crate::{AcledEvent, Region};
+
+impl Region {
+    /// The region ACLED assigns to a country or territory, by the name used
+    /// in the `country` column, e.g. `"Democratic Republic of Congo"`. The
+    /// comparison is case-insensitive.
+    ///
+    /// ```
+    /// use acled_api::Region;
+    ///
+    /// assert_eq!(Region::of_country("Mali"), Some(Region::WesternAfrica));
+    /// ```
+    pub fn of_country(country: &str) -> Option<Region> {
+        COUNTRY_REGIONS
+            .iter()
+            .find(|(name, _)| country.eq_ignore_ascii_case(name))
+            .map(|(_, region)| *region)
+    }
+}
+
+/// An event whose `region` disagrees with the region of its `country`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegionMismatch {
+    pub id: String,
+    pub country: String,
+    /// The region returned by the API.
+    pub reported: Region,
+    /// The region according to [`Region::of_country`].
+    pub expected: Region,
+}
+
+/// Find the events whose reported region disagrees with
+/// [`Region::of_country`], which usually indicates an upstream data glitch.
+/// Events from countries that aren't in the mapping are skipped.
+pub fn check_regions(events: &[AcledEvent]) -> Vec<RegionMismatch> {
+    events
+        .iter()
+        .filter_map(|event| {
+            let expected = Region::of_country(&event.country)?;
+            (expected != event.region).then(|| RegionMismatch {
+                id: event.id.clone(),
+                country: event.country.clone(),
+                reported: event.region,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// Country and territory names as used by ACLED, and their region.
+static COUNTRY_REGIONS: &[(&str, Region)] = &[
+    ("Benin", Region::WesternAfrica),
+    ("Burkina Faso", Region::WesternAfrica),
+    ("Cape Verde", Region::WesternAfrica),
+    ("Gambia", Region::WesternAfrica),
+    ("Ghana", Region::WesternAfrica),
+    ("Guinea", Region::WesternAfrica),
+    ("Guinea-Bissau", Region::WesternAfrica),
+    ("Ivory Coast", Region::WesternAfrica),
+    ("Liberia", Region::WesternAfrica),
+    ("Mali", Region::WesternAfrica),
+    ("Mauritania", Region::WesternAfrica),
+    ("Niger", Region::WesternAfrica),
+    ("Nigeria", Region::WesternAfrica),
+    (
+        "Saint Helena, Ascension and Tristan da Cunha",
+        Region::WesternAfrica,
+    ),
+    ("Senegal", Region::WesternAfrica),
+    ("Sierra Leone", Region::WesternAfrica),
+    ("Togo", Region::WesternAfrica),
+    ("Angola", Region::MiddleAfrica),
+    ("Cameroon", Region::MiddleAfrica),
+    ("Central African Republic", Region::MiddleAfrica),
+    ("Chad", Region::MiddleAfrica),
+    ("Democratic Republic of Congo", Region::MiddleAfrica),
+    ("Equatorial Guinea", Region::MiddleAfrica),
+    ("Gabon", Region::MiddleAfrica),
+    ("Republic of Congo", Region::MiddleAfrica),
+    ("Sao Tome and Principe", Region::MiddleAfrica),
+    ("Burundi", Region::EasternAfrica),
+    ("Comoros", Region::EasternAfrica),
+    ("Djibouti", Region::EasternAfrica),
+    ("Eritrea", Region::EasternAfrica),
+    ("Ethiopia", Region::EasternAfrica),
+    ("Kenya", Region::EasternAfrica),
+    ("Madagascar", Region::EasternAfrica),
+    ("Malawi", Region::EasternAfrica),
+    ("Mauritius", Region::EasternAfrica),
+    ("Mayotte", Region::EasternAfrica),
+    ("Mozambique", Region::EasternAfrica),
+    ("Reunion", Region::EasternAfrica),
+    ("Rwanda", Region::EasternAfrica),
+    ("Seychelles", Region::EasternAfrica),
+    ("Somalia", Region::EasternAfrica),
+    ("South Sudan", Region::EasternAfrica),
+    ("Tanzania", Region::EasternAfrica),
+    ("Uganda", Region::EasternAfrica),
+    ("Zambia", Region::EasternAfrica),
+    ("Zimbabwe", Region::EasternAfrica),
+    ("Botswana", Region::SouthernAfrica),
+    ("eSwatini", Region::SouthernAfrica),
+    ("Lesotho", Region::SouthernAfrica),
+    ("Namibia", Region::SouthernAfrica),
+    ("South Africa", Region::SouthernAfrica),
+    ("Algeria", Region::NorthernAfrica),
+    ("Egypt", Region::NorthernAfrica),
+    ("Libya", Region::NorthernAfrica),
+    ("Morocco", Region::NorthernAfrica),
+    ("Sudan", Region::NorthernAfrica),
+    ("Tunisia", Region::NorthernAfrica),
+    ("Afghanistan", Region::SouthAsia),
+    ("Bangladesh", Region::SouthAsia),
+    ("Bhutan", Region::SouthAsia),
+    ("India", Region::SouthAsia),
+    ("Maldives", Region::SouthAsia),
+    ("Nepal", Region::SouthAsia),
+    ("Pakistan", Region::SouthAsia),
+    ("Sri Lanka", Region::SouthAsia),
+    ("Brunei", Region::SoutheastAsia),
+    ("Cambodia", Region::SoutheastAsia),
+    ("East Timor", Region::SoutheastAsia),
+    ("Indonesia", Region::SoutheastAsia),
+    ("Laos", Region::SoutheastAsia),
+    ("Malaysia", Region::SoutheastAsia),
+    ("Myanmar", Region::SoutheastAsia),
+    ("Philippines", Region::SoutheastAsia),
+    ("Singapore", Region::SoutheastAsia),
+    ("Thailand", Region::SoutheastAsia),
+    ("Vietnam", Region::SoutheastAsia),
+    ("Bahrain", Region::MiddleEast),
+    ("Iran", Region::MiddleEast),
+    ("Iraq", Region::MiddleEast),
+    ("Israel", Region::MiddleEast),
+    ("Jordan", Region::MiddleEast),
+    ("Kuwait", Region::MiddleEast),
+    ("Lebanon", Region::MiddleEast),
+    ("Oman", Region::MiddleEast),
+    ("Palestine", Region::MiddleEast),
+    ("Qatar", Region::MiddleEast),
+    ("Saudi Arabia", Region::MiddleEast),
+    ("Syria", Region::MiddleEast),
+    ("Turkey", Region::MiddleEast),
+    ("United Arab Emirates", Region::MiddleEast),
+    ("Yemen", Region::MiddleEast),
+    ("Albania", Region::Europe),
+    ("Andorra", Region::Europe),
+    ("Austria", Region::Europe),
+    ("Belarus", Region::Europe),
+    ("Belgium", Region::Europe),
+    ("Bosnia and Herzegovina", Region::Europe),
+    ("Bulgaria", Region::Europe),
+    ("Croatia", Region::Europe),
+    ("Cyprus", Region::Europe),
+    ("Czech Republic", Region::Europe),
+    ("Denmark", Region::Europe),
+    ("Estonia", Region::Europe),
+    ("Faroe Islands", Region::Europe),
+    ("Finland", Region::Europe),
+    ("France", Region::Europe),
+    ("Germany", Region::Europe),
+    ("Gibraltar", Region::Europe),
+    ("Greece", Region::Europe),
+    ("Guernsey", Region::Europe),
+    ("Hungary", Region::Europe),
+    ("Iceland", Region::Europe),
+    ("Ireland", Region::Europe),
+    ("Isle of Man", Region::Europe),
+    ("Italy", Region::Europe),
+    ("Jersey", Region::Europe),
+    ("Kosovo", Region::Europe),
+    ("Latvia", Region::Europe),
+    ("Liechtenstein", Region::Europe),
+    ("Lithuania", Region::Europe),
+    ("Luxembourg", Region::Europe),
+    ("Malta", Region::Europe),
+    ("Moldova", Region::Europe),
+    ("Monaco", Region::Europe),
+    ("Montenegro", Region::Europe),
+    ("Netherlands", Region::Europe),
+    ("North Macedonia", Region::Europe),
+    ("Norway", Region::Europe),
+    ("Poland", Region::Europe),
+    ("Portugal", Region::Europe),
+    ("Romania", Region::Europe),
+    ("Russia", Region::Europe),
+    ("San Marino", Region::Europe),
+    ("Serbia", Region::Europe),
+    ("Slovakia", Region::Europe),
+    ("Slovenia", Region::Europe),
+    ("Spain", Region::Europe),
+    ("Sweden", Region::Europe),
+    ("Switzerland", Region::Europe),
+    ("Ukraine", Region::Europe),
+    ("United Kingdom", Region::Europe),
+    ("Vatican City", Region::Europe),
+    ("Armenia", Region::CaucasusAndCentralAsia),
+    ("Azerbaijan", Region::CaucasusAndCentralAsia),
+    ("Georgia", Region::CaucasusAndCentralAsia),
+    ("Kazakhstan", Region::CaucasusAndCentralAsia),
+    ("Kyrgyzstan", Region::CaucasusAndCentralAsia),
+    ("Tajikistan", Region::CaucasusAndCentralAsia),
+    ("Turkmenistan", Region::CaucasusAndCentralAsia),
+    ("Uzbekistan", Region::CaucasusAndCentralAsia),
+    ("Belize", Region::CentralAmerica),
+    ("Costa Rica", Region::CentralAmerica),
+    ("El Salvador", Region::CentralAmerica),
+    ("Guatemala", Region::CentralAmerica),
+    ("Honduras", Region::CentralAmerica),
+    ("Mexico", Region::CentralAmerica),
+    ("Nicaragua", Region::CentralAmerica),
+    ("Panama", Region::CentralAmerica),
+    ("Argentina", Region::SouthAmerica),
+    ("Bolivia", Region::SouthAmerica),
+    ("Brazil", Region::SouthAmerica),
+    ("Chile", Region::SouthAmerica),
+    ("Colombia", Region::SouthAmerica),
+    ("Ecuador", Region::SouthAmerica),
+    ("Falkland Islands", Region::SouthAmerica),
+    ("French Guiana", Region::SouthAmerica),
+    ("Guyana", Region::SouthAmerica),
+    ("Paraguay", Region::SouthAmerica),
+    ("Peru", Region::SouthAmerica),
+    (
+        "South Georgia and the South Sandwich Islands",
+        Region::SouthAmerica,
+    ),
+    ("Suriname", Region::SouthAmerica),
+    ("Uruguay", Region::SouthAmerica),
+    ("Venezuela", Region::SouthAmerica),
+    ("Anguilla", Region::Caribbean),
+    ("Antigua and Barbuda", Region::Caribbean),
+    ("Aruba", Region::Caribbean),
+    ("Bahamas", Region::Caribbean),
+    ("Barbados", Region::Caribbean),
+    ("Bonaire, Saint Eustatius and Saba", Region::Caribbean),
+    ("British Virgin Islands", Region::Caribbean),
+    ("Cayman Islands", Region::Caribbean),
+    ("Cuba", Region::Caribbean),
+    ("Curacao", Region::Caribbean),
+    ("Dominica", Region::Caribbean),
+    ("Dominican Republic", Region::Caribbean),
+    ("Grenada", Region::Caribbean),
+    ("Guadeloupe", Region::Caribbean),
+    ("Haiti", Region::Caribbean),
+    ("Jamaica", Region::Caribbean),
+    ("Martinique", Region::Caribbean),
+    ("Montserrat", Region::Caribbean),
+    ("Puerto Rico", Region::Caribbean),
+    ("Saint Kitts and Nevis", Region::Caribbean),
+    ("Saint Lucia", Region::Caribbean),
+    ("Saint Vincent and the Grenadines", Region::Caribbean),
+    ("Saint-Barthelemy", Region::Caribbean),
+    ("Saint-Martin", Region::Caribbean),
+    ("Sint Maarten", Region::Caribbean),
+    ("Trinidad and Tobago", Region::Caribbean),
+    ("Turks and Caicos Islands", Region::Caribbean),
+    ("Virgin Islands, U.S.", Region::Caribbean),
+    ("China", Region::EastAsia),
+    ("Japan", Region::EastAsia),
+    ("Mongolia", Region::EastAsia),
+    ("North Korea", Region::EastAsia),
+    ("South Korea", Region::EastAsia),
+    ("Taiwan", Region::EastAsia),
+    ("Bermuda", Region::NorthAmerica),
+    ("Canada", Region::NorthAmerica),
+    ("Greenland", Region::NorthAmerica),
+    ("Saint Pierre and Miquelon", Region::NorthAmerica),
+    ("United States", Region::NorthAmerica),
+    ("American Samoa", Region::Oceania),
+    ("Australia", Region::Oceania),
+    ("Cook Islands", Region::Oceania),
+    ("Fiji", Region::Oceania),
+    ("French Polynesia", Region::Oceania),
+    ("Guam", Region::Oceania),
+    ("Kiribati", Region::Oceania),
+    ("Marshall Islands", Region::Oceania),
+    ("Micronesia", Region::Oceania),
+    ("Nauru", Region::Oceania),
+    ("New Caledonia", Region::Oceania),
+    ("New Zealand", Region::Oceania),
+    ("Niue", Region::Oceania),
+    ("Norfolk Island", Region::Oceania),
+    ("Northern Mariana Islands", Region::Oceania),
+    ("Palau", Region::Oceania),
+    ("Papua New Guinea", Region::Oceania),
+    ("Pitcairn", Region::Oceania),
+    ("Samoa", Region::Oceania),
+    ("Solomon Islands", Region::Oceania),
+    ("Tokelau", Region::Oceania),
+    ("Tonga", Region::Oceania),
+    ("Tuvalu", Region::Oceania),
+    ("Vanuatu", Region::Oceania),
+    ("Wallis and Futuna", Region::Oceania),
+    ("Antarctica", Region::Antarctica),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn of_country_test() {
+        assert_eq!(
+            Region::of_country("democratic republic of congo"),
+            Some(Region::MiddleAfrica)
+        );
+        assert_eq!(
+            Region::of_country("Georgia"),
+            Some(Region::CaucasusAndCentralAsia)
+        );
+        assert_eq!(Region::of_country("Atlantis"), None);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{AcledEvent, Error, Where};
+
+/// The upload timestamp up to which events have been processed, e.g. for
+/// incrementally fetching new and updated events with the `timestamp` filter.
+///
+/// A cursor can only move forward, and remembers where each advance came
+/// from, so an accidental reset can't silently cause data to be skipped
+/// or re-processed.
+///
+/// ```
+/// use acled_api::{AcledQuery, Cursor};
+///
+/// let mut cursor = Cursor::new(1710025200);
+/// let query = AcledQuery {
+///   timestamp: cursor.filter(),
+///   ..Default::default()
+/// };
+/// // ... fetch events ...
+/// cursor.advance(1710111600, "nightly sync").unwrap();
+/// assert!(cursor.advance(0, "bug").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cursor {
+    timestamp: u64,
+    history: Vec<CursorAdvance>,
+}
+
+/// A single forward move of a [`Cursor`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CursorAdvance {
+    pub from: u64,
+    pub to: u64,
+    /// A description of what caused the advance.
+    pub source: String,
+}
+
+impl Cursor {
+    pub fn new(timestamp: u64) -> Cursor {
+        Cursor {
+            timestamp,
+            history: Vec::new(),
+        }
+    }
+
+    /// The current unix timestamp of the cursor.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// All advances of this cursor, oldest first.
+    pub fn history(&self) -> &[CursorAdvance] {
+        &self.history
+    }
+
+    /// Move the cursor forward to `timestamp`. Moving to the current
+    /// timestamp does nothing, moving backwards is an error.
+    pub fn advance(&mut self, timestamp: u64, source: impl Into<String>) -> Result<(), Error> {
+        if timestamp < self.timestamp {
+            return Err(Error::CursorMovedBackwards {
+                current: self.timestamp,
+                requested: timestamp,
+            });
+        }
+        if timestamp > self.timestamp {
+            self.history.push(CursorAdvance {
+                from: self.timestamp,
+                to: timestamp,
+                source: source.into(),
+            });
+            self.timestamp = timestamp;
+        }
+        Ok(())
+    }
+
+    /// Move the cursor forward to the newest timestamp of `events`. Events
+    /// older than the cursor are ignored.
+    pub fn advance_to_latest(
+        &mut self,
+        events: &[AcledEvent],
+        source: impl Into<String>,
+    ) -> Result<(), Error> {
+        let latest = events
+            .iter()
+            .map(|event| event.timestamp)
+            .fold(self.timestamp, u64::max);
+        self.advance(latest, source)
+    }
+
+    /// A filter for the `timestamp` field of a query that matches all
+    /// events uploaded at or after the cursor.
+    ///
+    /// Events uploaded in the same second as the cursor are included again,
+    /// because otherwise events uploaded in that second after the previous
+    /// fetch would be missed.
+    pub fn filter(&self) -> Where<u64> {
+        Where::GreaterThanOrEqual(self.timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_test() {
+        let mut cursor = Cursor::new(100);
+        cursor.advance(100, "noop").unwrap();
+        assert!(cursor.history().is_empty());
+
+        cursor.advance(200, "first").unwrap();
+        cursor.advance(300, "second").unwrap();
+        assert!(matches!(
+            cursor.advance(0, "reset"),
+            Err(Error::CursorMovedBackwards {
+                current: 300,
+                requested: 0
+            })
+        ));
+        assert_eq!(cursor.timestamp(), 300);
+        assert_eq!(
+            cursor.history(),
+            &[
+                CursorAdvance {
+                    from: 100,
+                    to: 200,
+                    source: "first".into()
+                },
+                CursorAdvance {
+                    from: 200,
+                    to: 300,
+                    source: "second".into()
+                }
+            ]
+        );
+    }
+}
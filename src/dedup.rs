@@ -0,0 +1,149 @@
+use crate::AcledEvent;
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, HashSet};
+
+/// Flags pairs of events that might describe the same incident, e.g. when
+/// it was reported by several sources. Events are only compared to other
+/// events on the same day.
+///
+/// ```
+/// # use acled_api::{AcledEvent, Deduplicator};
+/// # fn example(events: &[AcledEvent]) {
+/// for candidate in Deduplicator::default().candidates(events) {
+///     println!("{} and {} might be duplicates", candidate.first, candidate.second);
+/// }
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Deduplicator {
+    /// The maximum distance between two events, in kilometers.
+    pub max_distance_km: f64,
+    /// The minimum similarity of the notes and actors, from 0 to 1. See
+    /// [`DuplicateCandidate::similarity`].
+    pub min_similarity: f64,
+}
+
+impl Default for Deduplicator {
+    fn default() -> Self {
+        Deduplicator {
+            max_distance_km: 10.0,
+            min_similarity: 0.6,
+        }
+    }
+}
+
+/// A pair of events that might be duplicates, for manual review.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuplicateCandidate {
+    /// The id of the first event.
+    pub first: String,
+    /// The id of the second event.
+    pub second: String,
+    pub date: NaiveDate,
+    /// The distance between both events, in kilometers.
+    pub distance_km: f64,
+    /// The share of words that the notes and actors of both events have in
+    /// common (Jaccard index).
+    pub similarity: f64,
+}
+
+impl Deduplicator {
+    /// Find all pairs of potential duplicates, ordered by date. The events
+    /// themselves are left untouched.
+    pub fn candidates(&self, events: &[AcledEvent]) -> Vec<DuplicateCandidate> {
+        let mut by_date: BTreeMap<NaiveDate, Vec<(&AcledEvent, HashSet<String>)>> = BTreeMap::new();
+        for event in events {
+            by_date
+                .entry(event.date)
+                .or_default()
+                .push((event, words(event)));
+        }
+
+        let mut candidates = Vec::new();
+        for (date, events) in by_date {
+            for (i, (first, first_words)) in events.iter().enumerate() {
+                for (second, second_words) in &events[i + 1..] {
+                    if first.id == second.id {
+                        continue;
+                    }
+
+                    let distance_km = haversine_km(
+                        (first.latitude, first.longitude),
+                        (second.latitude, second.longitude),
+                    );
+                    if distance_km > self.max_distance_km {
+                        continue;
+                    }
+
+                    let similarity = jaccard(first_words, second_words);
+                    if similarity >= self.min_similarity {
+                        candidates.push(DuplicateCandidate {
+                            first: first.id.clone(),
+                            second: second.id.clone(),
+                            date,
+                            distance_km,
+                            similarity,
+                        });
+                    }
+                }
+            }
+        }
+        candidates
+    }
+}
+
+/// The lowercase words of the note and the actors.
+fn words(event: &AcledEvent) -> HashSet<String> {
+    [&event.note, &event.actor1, &event.actor2]
+        .into_iter()
+        .flat_map(|text| text.split(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// The great-circle distance between two coordinates.
+fn haversine_km((lat1, lon1): (f64, f64), (lat2, lon2): (f64, f64)) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acled::tests::acled_event;
+
+    #[test]
+    fn candidates_test() {
+        let mut first = acled_event("MLI1");
+        first.note = "Military forces clashed with JNIM militants near Djenne.".into();
+        let mut second = acled_event("MLI2");
+        second.note = "Military forces clashed with JNIM militants close to Djenne.".into();
+        second.latitude += 0.01;
+        let mut far = acled_event("MLI3");
+        far.note = first.note.clone();
+        far.latitude += 1.0;
+        let mut other_day = acled_event("MLI4");
+        other_day.note = first.note.clone();
+        other_day.date = other_day.date.succ_opt().unwrap();
+
+        let candidates = Deduplicator::default().candidates(&[first, second, far, other_day]);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].first, "MLI1");
+        assert_eq!(candidates[0].second, "MLI2");
+        assert!((candidates[0].distance_km - 1.11).abs() < 0.01);
+        assert!(candidates[0].similarity > 0.8);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{response::DeletedData, Error, Where};
+use crate::{page::Paging, response::DeletedData, timestamp_to_datetime, Error, Where};
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// This struct is used for specifying the query parameters for the `deleted`
 /// endpoint. See <https://apidocs.acleddata.com/deleted_endpoint.html#query-filters>.
@@ -17,16 +18,57 @@ use crate::{response::DeletedData, Error, Where};
 pub struct DeletedQuery {
     pub id: Where<String>,
     pub timestamp: Where<u64>,
+    /// The date on which the deleted event took place. This filter is
+    /// undocumented, but supported by the API.
+    pub date: Where<NaiveDate>,
+    /// The country of the deleted event. Like `date`, this is undocumented.
+    pub country: Where<String>,
+    /// The maximum number of rows returned per page. Defaults to the
+    /// API's own limit of 5000 rows when unset.
+    pub limit: Option<u32>,
+    /// Stop fetching once at least this many events have been received.
+    /// Any surplus events are discarded. This is not sent to the API.
+    pub max_results: Option<usize>,
+    /// Stop fetching after this many pages. This is not sent to the API.
+    pub max_pages: Option<u32>,
 }
-// NOTE: undocumented but event_date=2024-02-15 also works, so maybe more as well?
 
 impl DeletedQuery {
+    pub(crate) fn paging(&self) -> Paging {
+        Paging {
+            limit: self.limit,
+            max_results: self.max_results,
+            max_pages: self.max_pages,
+        }
+    }
+
+    /// The query parameters that are sent to the API, sorted by name and
+    /// value. This doesn't include the credentials or the `page` parameter.
+    pub fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = self.as_parameters();
+        pairs.sort();
+        pairs
+    }
+
     pub(crate) fn as_parameters(&self) -> Vec<(String, String)> {
-        let DeletedQuery { id, timestamp } = self;
+        let DeletedQuery {
+            id,
+            timestamp,
+            date,
+            country,
+            limit,
+            max_results: _,
+            max_pages: _,
+        } = self;
 
         let mut parameters = Vec::new();
         parameters.extend_from_slice(&id.as_parameters("event_id_cnty"));
         parameters.extend_from_slice(&timestamp.as_parameters("deleted_timestamp"));
+        parameters.extend_from_slice(&date.as_parameters("event_date"));
+        parameters.extend_from_slice(&country.as_parameters("country"));
+        if let Some(limit) = limit {
+            parameters.push(("limit".into(), limit.to_string()));
+        }
         parameters
     }
 }
@@ -34,7 +76,7 @@ impl DeletedQuery {
 /// An event returned by the `deleted` endpoint.
 ///
 /// Descriptions based on <https://apidocs.acleddata.com/deleted_endpoint.html>
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct DeletedEvent {
     /// An individual identifier by number and country acronym.
     ///
@@ -46,6 +88,13 @@ pub struct DeletedEvent {
     pub timestamp: u64,
 }
 
+impl DeletedEvent {
+    /// The `timestamp` as a date and time.
+    pub fn deleted_at(&self) -> DateTime<Utc> {
+        timestamp_to_datetime(self.timestamp)
+    }
+}
+
 impl TryFrom<DeletedData> for DeletedEvent {
     type Error = Error;
 
@@ -59,3 +108,57 @@ impl TryFrom<DeletedData> for DeletedEvent {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_test() {
+        let query = DeletedQuery {
+            timestamp: Where::GreaterThanOrEqual(1710025200),
+            date: Where::Between(
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            ),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.as_parameters(),
+            vec![
+                ("deleted_timestamp_where".into(), ">=".into()),
+                ("deleted_timestamp".into(), "1710025200".into()),
+                ("event_date_where".into(), "BETWEEN".into()),
+                ("event_date".into(), "2024-02-01|2024-02-29".into())
+            ]
+        );
+
+        assert_eq!(
+            query.to_query_pairs(),
+            vec![
+                ("deleted_timestamp".into(), "1710025200".into()),
+                ("deleted_timestamp_where".into(), ">=".into()),
+                ("event_date".into(), "2024-02-01|2024-02-29".into()),
+                ("event_date_where".into(), "BETWEEN".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn deleted_at_test() {
+        let event = DeletedEvent {
+            id: "MLI123".into(),
+            timestamp: 1710025200,
+        };
+        assert_eq!(
+            event.deleted_at(),
+            DateTime::parse_from_rfc3339("2024-03-09T23:00:00Z").unwrap()
+        );
+
+        let event = DeletedEvent {
+            id: "MLI123".into(),
+            timestamp: u64::MAX,
+        };
+        assert_eq!(event.deleted_at(), DateTime::<Utc>::MAX_UTC);
+    }
+}
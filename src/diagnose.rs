@@ -0,0 +1,110 @@
+use crate::response::{ACLED_FIELDS, ACLED_OPTIONAL_FIELDS};
+use serde_json::{Map, Value};
+
+/// A column whose JSON type differs from what this crate expects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldMismatch {
+    pub field: String,
+    pub expected: &'static str,
+    pub found: &'static str,
+}
+
+/// Result of comparing a row returned by the `acled` endpoint with the
+/// columns this crate knows how to parse. See [`crate::Api::diagnose`].
+///
+/// A column that was renamed upstream shows up as one `missing` and one
+/// `unknown` entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SchemaReport {
+    /// Columns returned by the API that this crate doesn't parse.
+    pub unknown: Vec<String>,
+    /// Columns this crate parses that the API didn't return.
+    pub missing: Vec<String>,
+    /// Columns that were returned with an unexpected JSON type.
+    pub mismatched: Vec<FieldMismatch>,
+}
+
+impl SchemaReport {
+    /// Returns `true` if the API response matches the expected schema.
+    pub fn is_ok(&self) -> bool {
+        self.unknown.is_empty() && self.missing.is_empty() && self.mismatched.is_empty()
+    }
+
+    pub(crate) fn from_row(row: &Map<String, Value>) -> SchemaReport {
+        let mut report = SchemaReport::default();
+
+        for (name, value) in row {
+            if !ACLED_FIELDS.contains(&name.as_str())
+                && !ACLED_OPTIONAL_FIELDS.contains(&name.as_str())
+            {
+                report.unknown.push(name.clone());
+                continue;
+            }
+
+            // Note: The API returns every column as a string, even numbers.
+            if !value.is_string() {
+                report.mismatched.push(FieldMismatch {
+                    field: name.clone(),
+                    expected: "string",
+                    found: json_type(value),
+                });
+            }
+        }
+
+        for name in ACLED_FIELDS {
+            if !row.contains_key(*name) {
+                report.missing.push((*name).to_owned());
+            }
+        }
+
+        report
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn report_test() {
+        let mut row = Map::new();
+        for name in ACLED_FIELDS {
+            row.insert((*name).to_owned(), json!(""));
+        }
+        assert!(SchemaReport::from_row(&row).is_ok());
+        let acled_row = crate::acled::tests::acled_row("MLI1", 1710025200);
+        let report = SchemaReport::from_row(acled_row.as_object().unwrap());
+        assert_eq!(report.unknown, vec!["new_column"]);
+        assert!(report.missing.is_empty());
+        row.insert("population_best".into(), json!("1200"));
+        assert!(SchemaReport::from_row(&row).is_ok());
+
+        row.remove("notes");
+        row.insert("latitude".into(), json!(12.5));
+        row.insert("new_column".into(), json!(""));
+        assert_eq!(
+            SchemaReport::from_row(&row),
+            SchemaReport {
+                unknown: vec!["new_column".into()],
+                missing: vec!["notes".into()],
+                mismatched: vec![FieldMismatch {
+                    field: "latitude".into(),
+                    expected: "string",
+                    found: "number",
+                }],
+            }
+        );
+    }
+}
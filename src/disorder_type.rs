@@ -0,0 +1,47 @@
+use strum::{Display, EnumString};
+
+use crate::{sealed, AsParameter};
+
+/// The disorder category an event belongs to.
+/// <https://apidocs.acleddata.com/acled_endpoint.html#disorder-types>
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, EnumString)]
+pub enum DisorderType {
+    /// Political violence
+    #[strum(to_string = "Political violence")]
+    PoliticalViolence,
+    /// Demonstrations
+    Demonstrations,
+    /// Strategic developments
+    #[strum(to_string = "Strategic developments")]
+    StrategicDevelopments,
+    /// Political violence and demonstrations, e.g. for excessive force
+    /// against protesters.
+    #[strum(to_string = "Political violence; Demonstrations")]
+    PoliticalViolenceDemonstrations,
+    /// A disorder category that this crate doesn't know about yet.
+    #[strum(default)]
+    Unknown(String),
+}
+
+impl AsParameter for DisorderType {
+    fn as_parameter(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl sealed::Sealed for DisorderType {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            "Political violence; Demonstrations".parse(),
+            Ok(DisorderType::PoliticalViolenceDemonstrations)
+        );
+        assert_eq!("Demonstrations".parse(), Ok(DisorderType::Demonstrations));
+        assert_eq!("Cyber".parse(), Ok(DisorderType::Unknown("Cyber".into())));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::page::Paging;
+use crate::Error;
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+
+/// An endpoint of the ACLED API, for endpoints that this crate doesn't
+/// support (yet). Use it with [`crate::Api::get`] to reuse authentication,
+/// pagination, retries and error handling.
+///
+/// ```
+/// use acled_api::{Endpoint, Error, Paging};
+///
+/// /// A hypothetical endpoint that returns one row per actor and year.
+/// struct ActorYears;
+///
+/// struct ActorYearsQuery {
+///     year: u32,
+/// }
+///
+/// impl Endpoint for ActorYears {
+///     const PATH: &'static str = "actoryear";
+///     type Query = ActorYearsQuery;
+///     type Row = serde_json::Map<String, serde_json::Value>;
+///     type Item = String;
+///
+///     fn parameters(query: &ActorYearsQuery) -> Vec<(String, String)> {
+///         vec![("year".into(), query.year.to_string())]
+///     }
+///
+///     fn convert(row: Self::Row) -> Result<String, Error> {
+///         row.get("actor_name")
+///             .and_then(|name| name.as_str())
+///             .map(String::from)
+///             .ok_or_else(|| Error::ParseError("actor_name".into()))
+///     }
+/// }
+///
+/// # fn example(api: &acled_api::Api) -> Result<(), Error> {
+/// let actors = api.get::<ActorYears>(&ActorYearsQuery { year: 2024 })?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Endpoint {
+    /// The name of the endpoint. Requests are sent to `/{PATH}/read`.
+    const PATH: &'static str;
+    /// The query type, e.g. [`crate::AcledQuery`].
+    type Query;
+    /// A single row as it is returned by the API.
+    type Row: DeserializeOwned;
+    /// The type that every row is converted into.
+    type Item;
+
+    /// The query parameters, without the credentials or the `page`.
+    fn parameters(query: &Self::Query) -> Vec<(String, String)>;
+
+    /// The pagination options of the query. Fetches all pages by default.
+    fn paging(_query: &Self::Query) -> Paging {
+        Paging::default()
+    }
+
+    /// Convert a single row.
+    fn convert(row: Self::Row) -> Result<Self::Item, Error>;
+}
+
+/// A row of the endpoint `E`.
+pub(crate) struct Row<E: Endpoint>(E::Row);
+
+impl<'de, E: Endpoint> Deserialize<'de> for Row<E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        E::Row::deserialize(deserializer).map(Row)
+    }
+}
+
+/// An item of the endpoint `E`, converted from a [`Row`].
+pub(crate) struct Item<E: Endpoint>(pub E::Item);
+
+impl<E: Endpoint> TryFrom<Row<E>> for Item<E> {
+    type Error = Error;
+
+    fn try_from(Row(row): Row<E>) -> Result<Self, Self::Error> {
+        E::convert(row).map(Item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    struct Numbers;
+
+    impl Endpoint for Numbers {
+        const PATH: &'static str = "numbers";
+        type Query = ();
+        type Row = serde_json::Value;
+        type Item = u64;
+
+        fn parameters(_query: &()) -> Vec<(String, String)> {
+            Vec::new()
+        }
+
+        fn convert(row: serde_json::Value) -> Result<u64, Error> {
+            row["number"]
+                .as_str()
+                .and_then(|number| number.parse().ok())
+                .ok_or_else(|| Error::ParseError("number".into()))
+        }
+    }
+
+    #[test]
+    fn endpoint_test() {
+        let body = br#"{"success":true,"count":2,"data":[{"number":"1"},{"number":"2"}]}"#;
+        let items = serde_json::from_slice::<Response<Row<Numbers>>>(body)
+            .unwrap()
+            .into::<Item<Numbers>>()
+            .unwrap();
+        assert_eq!(
+            items.into_iter().map(|Item(item)| item).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use strum::{Display, EnumString};
+
+use crate::{sealed, AsParameter};
+
+/// The type of an event.
+/// <https://apidocs.acleddata.com/acled_endpoint.html#event-types>
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, EnumString)]
+pub enum EventType {
+    Battles,
+    #[strum(to_string = "Explosions/Remote violence")]
+    ExplosionsRemoteViolence,
+    #[strum(to_string = "Violence against civilians")]
+    ViolenceAgainstCivilians,
+    Protests,
+    Riots,
+    #[strum(to_string = "Strategic developments")]
+    StrategicDevelopments,
+    /// An event type that this crate doesn't know about yet.
+    #[strum(default)]
+    Unknown(String),
+}
+
+impl AsParameter for EventType {
+    fn as_parameter(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl sealed::Sealed for EventType {}
+
+/// The subcategory of an event type. See [`SubEventType::event_type`] for
+/// the event type each subcategory belongs to.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, EnumString)]
+pub enum SubEventType {
+    // Battles
+    #[strum(to_string = "Armed clash")]
+    ArmedClash,
+    #[strum(to_string = "Government regains territory")]
+    GovernmentRegainsTerritory,
+    #[strum(to_string = "Non-state actor overtakes territory")]
+    NonStateActorOvertakesTerritory,
+
+    // Explosions/Remote violence
+    #[strum(to_string = "Chemical weapon")]
+    ChemicalWeapon,
+    #[strum(to_string = "Air/drone strike")]
+    AirDroneStrike,
+    #[strum(to_string = "Suicide bomb")]
+    SuicideBomb,
+    #[strum(to_string = "Shelling/artillery/missile attack")]
+    ShellingArtilleryMissileAttack,
+    #[strum(to_string = "Remote explosive/landmine/IED")]
+    RemoteExplosiveLandmineIed,
+    Grenade,
+
+    // Violence against civilians
+    #[strum(to_string = "Sexual violence")]
+    SexualViolence,
+    Attack,
+    #[strum(to_string = "Abduction/forced disappearance")]
+    AbductionForcedDisappearance,
+
+    // Protests
+    #[strum(to_string = "Peaceful protest")]
+    PeacefulProtest,
+    #[strum(to_string = "Protest with intervention")]
+    ProtestWithIntervention,
+    #[strum(to_string = "Excessive force against protesters")]
+    ExcessiveForceAgainstProtesters,
+
+    // Riots
+    #[strum(to_string = "Violent demonstration")]
+    ViolentDemonstration,
+    #[strum(to_string = "Mob violence")]
+    MobViolence,
+
+    // Strategic developments
+    Agreement,
+    Arrests,
+    #[strum(to_string = "Change to group/activity")]
+    ChangeToGroupActivity,
+    #[strum(to_string = "Disrupted weapons use")]
+    DisruptedWeaponsUse,
+    #[strum(to_string = "Headquarters or base established")]
+    HeadquartersOrBaseEstablished,
+    #[strum(to_string = "Looting/property destruction")]
+    LootingPropertyDestruction,
+    #[strum(to_string = "Non-violent transfer of territory")]
+    NonViolentTransferOfTerritory,
+    Other,
+
+    /// A subcategory that this crate doesn't know about yet. Not to be
+    /// confused with [`SubEventType::Other`], which is an actual subcategory
+    /// of strategic developments.
+    #[strum(default)]
+    Unknown(String),
+}
+
+impl SubEventType {
+    /// The event type this subcategory belongs to, if it is known.
+    pub fn event_type(&self) -> Option<EventType> {
+        Some(match self {
+            Self::ArmedClash
+            | Self::GovernmentRegainsTerritory
+            | Self::NonStateActorOvertakesTerritory => EventType::Battles,
+            Self::ChemicalWeapon
+            | Self::AirDroneStrike
+            | Self::SuicideBomb
+            | Self::ShellingArtilleryMissileAttack
+            | Self::RemoteExplosiveLandmineIed
+            | Self::Grenade => EventType::ExplosionsRemoteViolence,
+            Self::SexualViolence | Self::Attack | Self::AbductionForcedDisappearance => {
+                EventType::ViolenceAgainstCivilians
+            }
+            Self::PeacefulProtest
+            | Self::ProtestWithIntervention
+            | Self::ExcessiveForceAgainstProtesters => EventType::Protests,
+            Self::ViolentDemonstration | Self::MobViolence => EventType::Riots,
+            Self::Agreement
+            | Self::Arrests
+            | Self::ChangeToGroupActivity
+            | Self::DisruptedWeaponsUse
+            | Self::HeadquartersOrBaseEstablished
+            | Self::LootingPropertyDestruction
+            | Self::NonViolentTransferOfTerritory
+            | Self::Other => EventType::StrategicDevelopments,
+            Self::Unknown(_) => return None,
+        })
+    }
+
+    /// Whether this subcategory can belong to `event_type`. Unknown event
+    /// types and subcategories are always accepted.
+    pub fn belongs_to(&self, event_type: &EventType) -> bool {
+        match (self.event_type(), event_type) {
+            (_, EventType::Unknown(_)) | (None, _) => true,
+            (Some(expected), event_type) => expected == *event_type,
+        }
+    }
+}
+
+impl AsParameter for SubEventType {
+    fn as_parameter(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl sealed::Sealed for SubEventType {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!("Battles".parse(), Ok(EventType::Battles));
+        assert_eq!(
+            "Cyber attacks".parse(),
+            Ok(EventType::Unknown("Cyber attacks".into()))
+        );
+        assert_eq!(
+            EventType::Unknown("Cyber attacks".into()).to_string(),
+            "Cyber attacks"
+        );
+        assert_eq!("Other".parse(), Ok(SubEventType::Other));
+
+        assert!(SubEventType::ArmedClash.belongs_to(&EventType::Battles));
+        assert!(!SubEventType::ArmedClash.belongs_to(&EventType::Riots));
+        assert!(SubEventType::Unknown("Hacking".into()).belongs_to(&EventType::Riots));
+        assert!(SubEventType::Grenade.belongs_to(&EventType::Unknown("Cyber attacks".into())));
+    }
+}
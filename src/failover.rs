@@ -0,0 +1,178 @@
+use crate::Error;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// An ordered list of base URLs, the first one being the primary. Requests
+/// go to the active URL; after `threshold` consecutive failures the next URL
+/// becomes active. While a fallback is active, the primary is probed again
+/// every `probe_interval` and becomes active again once it responds.
+///
+/// See [`crate::Api::with_base_urls`].
+#[derive(Debug)]
+pub(crate) struct BaseUrls {
+    urls: Vec<String>,
+    threshold: u32,
+    probe_interval: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    active: usize,
+    failures: u32,
+    /// When the primary was last tried, while a fallback is active.
+    last_probe: Instant,
+}
+
+pub(crate) const DEFAULT_THRESHOLD: u32 = 3;
+pub(crate) const DEFAULT_PROBE_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Check that `url` is an absolute `http` or `https` URL, so building request
+/// URLs from it can't fail later.
+pub(crate) fn parse_base_url(url: &str) -> Result<String, Error> {
+    match Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => {
+            Ok(url.trim_end_matches('/').to_owned())
+        }
+        _ => Err(Error::InvalidConfiguration(format!(
+            "invalid base URL `{url}`"
+        ))),
+    }
+}
+
+impl BaseUrls {
+    /// Fails with [`Error::InvalidConfiguration`] if `urls` is empty or one of
+    /// them is invalid, see [`parse_base_url`].
+    pub(crate) fn new(urls: Vec<String>) -> Result<BaseUrls, Error> {
+        if urls.is_empty() {
+            return Err(Error::InvalidConfiguration("no base URL".into()));
+        }
+        Ok(BaseUrls {
+            urls: urls
+                .iter()
+                .map(|url| parse_base_url(url))
+                .collect::<Result<_, _>>()?,
+            threshold: DEFAULT_THRESHOLD,
+            probe_interval: DEFAULT_PROBE_INTERVAL,
+            state: Mutex::new(State {
+                active: 0,
+                failures: 0,
+                last_probe: Instant::now(),
+            }),
+        })
+    }
+
+    pub(crate) fn with_failover(mut self, threshold: u32, probe_interval: Duration) -> BaseUrls {
+        self.threshold = threshold.max(1);
+        self.probe_interval = probe_interval;
+        self
+    }
+
+    /// The index and URL the next request should use.
+    pub(crate) fn pick(&self) -> (usize, &str) {
+        let mut state = self.state.lock().unwrap();
+        let index = if state.active != 0 && state.last_probe.elapsed() >= self.probe_interval {
+            state.last_probe = Instant::now();
+            0
+        } else {
+            state.active
+        };
+        (index, &self.urls[index])
+    }
+
+    /// Record whether a request to the URL at `index` succeeded.
+    pub(crate) fn report(&self, index: usize, success: bool) {
+        let mut state = self.state.lock().unwrap();
+        if success {
+            if index == 0 || index == state.active {
+                state.active = index;
+                state.failures = 0;
+            }
+            return;
+        }
+
+        // Note: A failed probe of the primary doesn't count against the
+        // active fallback.
+        if index != state.active {
+            return;
+        }
+        state.failures += 1;
+        if state.failures >= self.threshold && self.urls.len() > 1 {
+            state.active = (state.active + 1) % self.urls.len();
+            state.failures = 0;
+            state.last_probe = Instant::now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_urls(probe_interval: Duration) -> BaseUrls {
+        BaseUrls::new(vec![
+            "https://primary.example.com/".into(),
+            "https://mirror.example.com".into(),
+        ])
+        .unwrap()
+        .with_failover(2, probe_interval)
+    }
+
+    #[test]
+    fn parse_base_url_test() {
+        assert_eq!(
+            parse_base_url("http://localhost:8080/").unwrap(),
+            "http://localhost:8080"
+        );
+        assert_eq!(
+            parse_base_url("https://acleddata.com/api").unwrap(),
+            "https://acleddata.com/api"
+        );
+        assert_eq!(
+            BaseUrls::new(Vec::new()).err().unwrap().to_string(),
+            "invalid configuration: no base URL"
+        );
+        for url in ["localhost:8080", "not a url", "ftp://example.com", ""] {
+            assert_eq!(
+                parse_base_url(url).err().unwrap().to_string(),
+                format!("invalid configuration: invalid base URL `{url}`")
+            );
+        }
+    }
+
+    #[test]
+    fn failover_test() {
+        let urls = base_urls(Duration::from_secs(3600));
+        assert_eq!(urls.pick(), (0, "https://primary.example.com"));
+
+        urls.report(0, false);
+        assert_eq!(urls.pick().0, 0);
+        urls.report(0, true);
+        urls.report(0, false);
+        assert_eq!(urls.pick().0, 0);
+        urls.report(0, false);
+        assert_eq!(urls.pick(), (1, "https://mirror.example.com"));
+
+        // Wraps around to the primary once every URL failed.
+        urls.report(1, false);
+        urls.report(1, false);
+        assert_eq!(urls.pick().0, 0);
+    }
+
+    #[test]
+    fn fail_back_test() {
+        let urls = base_urls(Duration::ZERO);
+        urls.report(0, false);
+        urls.report(0, false);
+
+        // Every request probes the primary, a failed probe keeps the mirror.
+        assert_eq!(urls.pick().0, 0);
+        urls.report(0, false);
+        assert_eq!(urls.state.lock().unwrap().active, 1);
+
+        assert_eq!(urls.pick().0, 0);
+        urls.report(0, true);
+        assert_eq!(urls.state.lock().unwrap().active, 0);
+    }
+}
@@ -0,0 +1,45 @@
+use strum::{Display, EnumString};
+
+/// The columns of the `acled` endpoint, used to select which columns are
+/// returned with [`crate::AcledQuery::fields`].
+/// <https://apidocs.acleddata.com/acled_endpoint.html#returned-data>
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum Field {
+    #[strum(to_string = "event_id_cnty")]
+    Id,
+    #[strum(to_string = "event_date")]
+    Date,
+    Year,
+    TimePrecision,
+    DisorderType,
+    EventType,
+    SubEventType,
+    Actor1,
+    #[strum(to_string = "assoc_actor_1")]
+    AssocActor1,
+    Inter1,
+    Actor2,
+    #[strum(to_string = "assoc_actor_2")]
+    AssocActor2,
+    Inter2,
+    Interaction,
+    CivilianTargeting,
+    Iso,
+    Region,
+    Country,
+    Admin1,
+    Admin2,
+    Admin3,
+    Location,
+    Latitude,
+    Longitude,
+    GeoPrecision,
+    Source,
+    SourceScale,
+    #[strum(to_string = "notes")]
+    Note,
+    Fatalities,
+    Tags,
+    Timestamp,
+}
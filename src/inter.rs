@@ -0,0 +1,200 @@
+use std::hash::{Hash, Hasher};
+use strum::{Display, EnumString};
+
+use crate::{sealed, AsParameter};
+
+/// Numeric codes for the category of an actor, used by the `inter1` and
+/// `inter2` columns.
+/// <https://apidocs.acleddata.com/acled_endpoint.html#inter-codes>
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display, EnumString)]
+pub enum Inter {
+    /// State forces
+    #[strum(to_string = "State forces")]
+    StateForces = 1,
+    /// Rebel groups
+    #[strum(to_string = "Rebel groups")]
+    RebelGroups = 2,
+    /// Political militias
+    #[strum(to_string = "Political militias")]
+    PoliticalMilitias = 3,
+    /// Identity militias
+    #[strum(to_string = "Identity militias")]
+    IdentityMilitias = 4,
+    /// Rioters
+    Rioters = 5,
+    /// Protesters
+    Protesters = 6,
+    /// Civilians
+    Civilians = 7,
+    /// External/Other forces
+    #[strum(to_string = "External/Other forces")]
+    ExternalOtherForces = 8,
+}
+
+impl Inter {
+    /// The actor category for a numeric code.
+    pub fn from_code(code: u8) -> Option<Inter> {
+        Some(match code {
+            1 => Self::StateForces,
+            2 => Self::RebelGroups,
+            3 => Self::PoliticalMilitias,
+            4 => Self::IdentityMilitias,
+            5 => Self::Rioters,
+            6 => Self::Protesters,
+            7 => Self::Civilians,
+            8 => Self::ExternalOtherForces,
+            _ => return None,
+        })
+    }
+
+    /// The numeric code of this actor category.
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Parse an `inter1`/`inter2` column, which contains either the numeric
+    /// code or the name of the category. `0` or an empty value mean that
+    /// there is no such actor.
+    pub(crate) fn parse_column(value: &str) -> Option<Option<Inter>> {
+        match value {
+            "" | "0" => Some(None),
+            _ => match value.parse::<u8>() {
+                Ok(code) => Inter::from_code(code).map(Some),
+                Err(_) => value.parse().ok().map(Some),
+            },
+        }
+    }
+}
+
+impl AsParameter for Inter {
+    fn as_parameter(&self) -> String {
+        // Note: The query strings use the numeric code.
+        self.code().to_string()
+    }
+}
+
+impl sealed::Sealed for Inter {}
+
+/// The combination of the actor categories of both actors of an event,
+/// used by the `interaction` column. For example, state forces against
+/// civilians is `Interaction::new(Inter::StateForces, Some(Inter::Civilians))`.
+///
+/// The order of the two actors doesn't matter, interactions are compared
+/// and hashed by their [`Interaction::code`].
+#[derive(Copy, Clone, Debug)]
+pub struct Interaction {
+    pub actor1: Inter,
+    /// `None` for events with only a single actor.
+    pub actor2: Option<Inter>,
+}
+
+impl Interaction {
+    pub fn new(actor1: Inter, actor2: Option<Inter>) -> Interaction {
+        Interaction { actor1, actor2 }
+    }
+
+    /// The interaction for a two digit code, see [`Interaction::code`].
+    pub fn from_code(code: u8) -> Option<Interaction> {
+        let actor1 = Inter::from_code(code / 10)?;
+        let actor2 = match code % 10 {
+            0 => None,
+            actor2 => Some(Inter::from_code(actor2)?),
+        };
+        Some(Interaction { actor1, actor2 })
+    }
+
+    /// Parse an `interaction` column, which contains the two digit code.
+    /// An empty value means that the column isn't filled in for the event.
+    pub(crate) fn parse_column(value: &str) -> Option<Option<Interaction>> {
+        match value {
+            "" => Some(None),
+            _ => value
+                .parse()
+                .ok()
+                .and_then(Interaction::from_code)
+                .map(Some),
+        }
+    }
+
+    /// The two digit interaction code, with the lower actor code first.
+    /// Events with only a single actor use 0 as the second digit.
+    pub fn code(&self) -> u8 {
+        let a = self.actor1.code();
+        match self.actor2.map(|actor2| actor2.code()) {
+            None => a * 10,
+            Some(b) => a.min(b) * 10 + a.max(b),
+        }
+    }
+}
+
+impl PartialEq for Interaction {
+    fn eq(&self, other: &Interaction) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Interaction {}
+
+impl Hash for Interaction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state);
+    }
+}
+
+impl AsParameter for Interaction {
+    fn as_parameter(&self) -> String {
+        self.code().to_string()
+    }
+}
+
+impl sealed::Sealed for Interaction {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Inter::parse_column("7"), Some(Some(Inter::Civilians)));
+        assert_eq!(
+            Inter::parse_column("State forces"),
+            Some(Some(Inter::StateForces))
+        );
+        assert_eq!(Inter::parse_column("0"), Some(None));
+        assert_eq!(Inter::parse_column(""), Some(None));
+        assert_eq!(Inter::parse_column("9"), None);
+
+        assert_eq!(
+            Interaction::from_code(17),
+            Some(Interaction::new(Inter::StateForces, Some(Inter::Civilians)))
+        );
+        assert_eq!(
+            Interaction::from_code(60),
+            Some(Interaction::new(Inter::Protesters, None))
+        );
+        assert_eq!(Interaction::from_code(90), None);
+        assert_eq!(Interaction::from_code(17).unwrap().code(), 17);
+
+        assert_eq!(
+            Interaction::parse_column("60"),
+            Some(Some(Interaction::new(Inter::Protesters, None)))
+        );
+        assert_eq!(Interaction::parse_column(""), Some(None));
+        assert_eq!(Interaction::parse_column("90"), None);
+        assert_eq!(Interaction::parse_column("State forces"), None);
+    }
+
+    #[test]
+    fn interaction_order_test() {
+        let forward = Interaction::new(Inter::StateForces, Some(Inter::Civilians));
+        let backward = Interaction::new(Inter::Civilians, Some(Inter::StateForces));
+        assert_eq!(forward, backward);
+        assert_eq!(Interaction::from_code(17), Some(backward));
+        assert_eq!(Interaction::from_code(71), Some(forward));
+        assert_eq!(backward.code(), 17);
+        assert_ne!(forward, Interaction::new(Inter::StateForces, None));
+
+        let set: std::collections::HashSet<_> = [forward, backward].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,199 @@
+//! Adapters that map [`AcledEvent`]s into the schemas of other conflict
+//! datasets, for comparative research. Only the columns that have a clear
+//! ACLED equivalent are filled.
+
+use crate::{AcledEvent, EventType, Inter};
+use chrono::NaiveDate;
+
+/// A subset of the UCDP Georeferenced Event Dataset (GED) schema.
+/// <https://ucdp.uu.se/downloads/ged/ged231.pdf>
+#[derive(Clone, Debug, PartialEq)]
+pub struct UcdpGedEvent {
+    /// The ACLED event id, in place of the UCDP id.
+    pub id: String,
+    pub date_start: NaiveDate,
+    pub date_end: NaiveDate,
+    pub country: String,
+    pub adm_1: String,
+    pub adm_2: String,
+    pub where_coordinates: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// The UCDP type of violence: 1 for state-based conflict, 2 for
+    /// non-state conflict and 3 for one-sided violence.
+    pub type_of_violence: Option<u8>,
+    pub side_a: String,
+    pub side_b: String,
+    pub source_article: String,
+    /// UCDP's best estimate of fatalities.
+    pub best: u32,
+}
+
+impl From<&AcledEvent> for UcdpGedEvent {
+    fn from(event: &AcledEvent) -> Self {
+        UcdpGedEvent {
+            id: event.id.clone(),
+            date_start: event.date,
+            date_end: event.date,
+            country: event.country.clone(),
+            adm_1: event.administrative_region.clone(),
+            adm_2: event.admin2.clone().unwrap_or_default(),
+            where_coordinates: event.location.clone().unwrap_or_default(),
+            latitude: event.latitude,
+            longitude: event.longitude,
+            type_of_violence: type_of_violence(event),
+            side_a: event.actor1.clone(),
+            side_b: event.actor2.clone(),
+            source_article: event.source.clone(),
+            best: event.fatalities,
+        }
+    }
+}
+
+/// An approximation of UCDP's type of violence. Demonstrations and
+/// strategic developments don't have an equivalent.
+fn type_of_violence(event: &AcledEvent) -> Option<u8> {
+    match event.event_type {
+        EventType::Battles | EventType::ExplosionsRemoteViolence => {
+            Some(match (event.inter1, event.inter2) {
+                (Inter::StateForces, _) | (_, Some(Inter::StateForces)) => 1,
+                (_, Some(Inter::Civilians)) => 3,
+                _ => 2,
+            })
+        }
+        EventType::ViolenceAgainstCivilians => Some(3),
+        _ => None,
+    }
+}
+
+/// A subset of the GDELT 2.0 event schema.
+/// <http://data.gdeltproject.org/documentation/GDELT-Event_Codebook-V2.0.pdf>
+#[derive(Clone, Debug, PartialEq)]
+pub struct GdeltEvent {
+    /// The ACLED event id, in place of `GLOBALEVENTID`.
+    pub global_event_id: String,
+    /// The date formatted as `YYYYMMDD`.
+    pub day: u32,
+    pub actor1_name: String,
+    pub actor2_name: String,
+    /// The QuadClass: 3 for verbal conflict and 4 for material conflict.
+    pub quad_class: u8,
+    pub action_geo_full_name: String,
+    pub action_geo_lat: f64,
+    pub action_geo_long: f64,
+    pub source_url: String,
+}
+
+impl From<&AcledEvent> for GdeltEvent {
+    fn from(event: &AcledEvent) -> Self {
+        let place = [
+            event.location.as_deref(),
+            Some(event.administrative_region.as_str()),
+            Some(event.country.as_str()),
+        ];
+        GdeltEvent {
+            global_event_id: event.id.clone(),
+            day: event
+                .date
+                .format("%Y%m%d")
+                .to_string()
+                .parse()
+                .unwrap_or_default(),
+            actor1_name: event.actor1.clone(),
+            actor2_name: event.actor2.clone(),
+            quad_class: match event.event_type {
+                EventType::Protests | EventType::StrategicDevelopments => 3,
+                _ => 4,
+            },
+            action_geo_full_name: place
+                .into_iter()
+                .flatten()
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(", "),
+            action_geo_lat: event.latitude,
+            action_geo_long: event.longitude,
+            source_url: event.source.clone(),
+        }
+    }
+}
+
+/// A key for matching the same incident across datasets: the date, the
+/// location rounded to `precision` decimal degrees, and the sorted actor
+/// names in lowercase.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchKey {
+    pub date: NaiveDate,
+    pub latitude: i64,
+    pub longitude: i64,
+    pub actors: Vec<String>,
+}
+
+impl MatchKey {
+    /// Build a key from the columns every dataset has in some form.
+    /// `precision` of 1 rounds to roughly 11 km.
+    pub fn new(
+        date: NaiveDate,
+        (latitude, longitude): (f64, f64),
+        actors: &[&str],
+        precision: u32,
+    ) -> MatchKey {
+        let scale = 10f64.powi(precision as i32);
+        let mut actors: Vec<String> = actors
+            .iter()
+            .map(|actor| actor.trim().to_lowercase())
+            .filter(|actor| !actor.is_empty())
+            .collect();
+        actors.sort();
+        MatchKey {
+            date,
+            latitude: (latitude * scale).round() as i64,
+            longitude: (longitude * scale).round() as i64,
+            actors,
+        }
+    }
+
+    /// The key of an ACLED event.
+    pub fn of_event(event: &AcledEvent, precision: u32) -> MatchKey {
+        MatchKey::new(
+            event.date,
+            (event.latitude, event.longitude),
+            &[&event.actor1, &event.actor2],
+            precision,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acled::tests::acled_event;
+
+    #[test]
+    fn interop_test() {
+        let event = acled_event("MLI1");
+
+        let ged = UcdpGedEvent::from(&event);
+        assert_eq!(ged.type_of_violence, Some(1));
+        assert_eq!(ged.side_a, "Military Forces of Mali (2021-)");
+        assert_eq!(ged.best, 3);
+
+        let gdelt = GdeltEvent::from(&event);
+        assert_eq!(gdelt.day, 20240301);
+        assert_eq!(gdelt.quad_class, 4);
+        assert_eq!(gdelt.action_geo_full_name, "Djenne, Mopti, Mali");
+
+        assert_eq!(
+            MatchKey::of_event(&event, 1),
+            MatchKey::new(
+                event.date,
+                (14.46, -4.2),
+                &[
+                    "JNIM: Group for Support of Islam and Muslims",
+                    "military forces of mali (2021-)"
+                ],
+                1
+            )
+        );
+    }
+}
@@ -1,27 +1,224 @@
 mod acled;
+mod actor;
+mod actor_type;
+mod api_builder;
+mod archive;
+mod auth;
+mod builder;
+mod cast;
+mod citation;
+mod country;
+mod country_region;
+mod cursor;
+mod dedup;
 mod deleted;
+mod diagnose;
+mod disorder_type;
+mod endpoint;
+mod event_type;
+mod failover;
+mod field;
+mod inter;
+mod interop;
+mod iso;
+mod page;
+mod paginator;
+mod precision;
+mod projection;
+mod quality;
+mod record;
 mod region;
+mod region_info;
 mod response;
+mod retry;
+mod sparse;
+mod summary;
+mod taxonomy;
+mod throttle;
+mod translate;
+mod transport;
 
-use crate::response::{AcledData, DeletedData, Response};
-use reqwest::Url;
+use crate::response::{
+    AcledData, ActorData, ActorTypeData, CastData, CountryData, DeletedData, RegionData, Response,
+    SparseData,
+};
+use crate::retry::RetryBudget;
+use crate::throttle::Throttle;
+use serde::de::DeserializeOwned;
+use std::io::Read;
+use std::ops::{Range, RangeFrom, RangeInclusive};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use url::Url;
 
-pub use crate::acled::{AcledEvent, AcledQuery};
+pub use crate::acled::{AcledEvent, AcledQuery, EventKey};
+pub use crate::actor::{Actor, ActorQuery};
+pub use crate::actor_type::{ActorType, ActorTypeQuery};
+pub use crate::api_builder::ApiBuilder;
+pub use crate::archive::{Archive, Delta, DeltaRecord, Manifest};
+use crate::auth::Auth;
+pub use crate::auth::Credentials;
+pub use crate::builder::{
+    AcledQueryBuilder, ActorQueryBuilder, ActorTypeQueryBuilder, CastQueryBuilder,
+    CountryQueryBuilder, DeletedQueryBuilder, RegionQueryBuilder,
+};
+pub use crate::cast::{CastForecast, CastQuery};
+pub use crate::citation::{ATTRIBUTION, DASHBOARD_URL};
+pub use crate::country::{Country, CountryQuery};
+pub use crate::country_region::{check_regions, RegionMismatch};
+pub use crate::cursor::{Cursor, CursorAdvance};
+pub use crate::dedup::{Deduplicator, DuplicateCandidate};
 pub use crate::deleted::{DeletedEvent, DeletedQuery};
+pub use crate::diagnose::{FieldMismatch, SchemaReport};
+pub use crate::disorder_type::DisorderType;
+pub use crate::endpoint::Endpoint;
+use crate::endpoint::{Item, Row};
+pub use crate::event_type::{EventType, SubEventType};
+use crate::failover::BaseUrls;
+pub use crate::field::Field;
+pub use crate::inter::{Inter, Interaction};
+pub use crate::interop::{GdeltEvent, MatchKey, UcdpGedEvent};
+pub use crate::page::{FetchStats, Page, Paging, Progress};
+pub use crate::paginator::Paginator;
+pub use crate::precision::{GeoPrecision, TimePrecision};
+#[doc(hidden)]
+pub use crate::projection::column as __column;
+use crate::projection::Projected;
+pub use crate::projection::{Columns, MinimalEvent, Projection};
+pub use crate::quality::Quality;
+pub use crate::record::EventRecord;
 pub use crate::region::Region;
-pub use chrono::NaiveDate;
+pub use crate::region_info::{RegionInfo, RegionQuery};
+pub use crate::sparse::SparseEvent;
+pub use crate::summary::{summarize_notes, FirstSentence, Summarizer};
+pub use crate::taxonomy::{tag_events, Taxonomy};
+pub use crate::translate::{translate_notes, TranslatedNote, Translator};
+#[cfg(feature = "reqwest")]
+pub use crate::transport::ReqwestTransport;
+pub use crate::transport::{HttpRequest, HttpResponse, HttpTransport, Method};
+use chrono::{DateTime, NaiveDateTime, Utc};
+pub use chrono::{Month, NaiveDate};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
-    #[error("HTTP request failed")]
+    #[cfg(feature = "reqwest")]
+    #[error("HTTP request failed: {0}")]
     ReqwestError(#[from] reqwest::Error),
 
+    /// The server could not be reached at all, e.g. because the DNS lookup
+    /// or the connection failed. See [`Error::is_offline`].
+    #[error("could not connect: {0}")]
+    ConnectError(Box<dyn std::error::Error + Send + Sync>),
+
+    /// An [`HttpTransport`] failed to send a request.
+    #[error("HTTP request failed: {0}")]
+    HttpError(Box<dyn std::error::Error + Send + Sync>),
+
+    /// The server responded with an unexpected HTTP status, e.g. a server
+    /// error (HTTP 5xx).
+    #[error("HTTP request failed with status {0}")]
+    HttpStatus(u16),
+
     /// Error that was returned by one of the API endpoints.
     #[error("API returned an error: {message}")]
     APIError { message: String },
 
     #[error("API response could not be parsed: {0}")]
     ParseError(String),
+
+    #[error("API response is not valid JSON: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    /// The options passed to [`ApiBuilder`] are invalid.
+    #[error("invalid configuration: {0}")]
+    InvalidConfiguration(String),
+
+    /// [`Configuration::from_env`] was missing some environment variables.
+    #[error("missing environment variables: {}", .0.join(", "))]
+    MissingEnvironment(Vec<&'static str>),
+
+    /// A [`Cursor`] was asked to move to an older timestamp.
+    #[error("cursor can't move backwards from {current} to {requested}")]
+    CursorMovedBackwards { current: u64, requested: u64 },
+
+    /// An archived pull could not be replayed.
+    #[error("archive could not be replayed: {0}")]
+    ArchiveError(String),
+
+    /// A [`Translator`] failed.
+    #[error("translation failed: {0}")]
+    TranslationError(String),
+
+    /// Another error, together with a description of the operation that
+    /// failed, e.g. the endpoint and page that were requested.
+    #[error("{context}: {source}")]
+    Context { context: String, source: Box<Error> },
+}
+
+impl Error {
+    /// Wrap the error with a description of the operation that failed.
+    pub fn context(self, context: impl Into<String>) -> Error {
+        Error::Context {
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Whether the error means that there is no network connection, as
+    /// opposed to e.g. a server error. Applications can use this to switch
+    /// to offline data instead of retrying.
+    pub fn is_offline(&self) -> bool {
+        match self.root_cause() {
+            Error::ConnectError(_) => true,
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestError(error) => error.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Whether retrying the request that failed might succeed, e.g. after a
+    /// connection error, a timeout, a server error (HTTP 5xx) or rate
+    /// limiting (HTTP 429). Errors like an invalid key or a response that
+    /// can't be parsed are not transient. See [`Api::with_retries`].
+    pub fn is_transient(&self) -> bool {
+        match self.root_cause() {
+            Error::ConnectError(_) => true,
+            Error::HttpStatus(status) => *status >= 500 || *status == 429,
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestError(error) => error.is_connect() || error.is_timeout(),
+            // Note: Reading the body fails with an I/O error, e.g. when the
+            // connection is dropped halfway.
+            Error::IoError(error) => matches!(
+                error.kind(),
+                std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::UnexpectedEof
+            ),
+            _ => false,
+        }
+    }
+
+    /// The innermost error, without any context.
+    pub fn root_cause(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root_cause(),
+            error => error,
+        }
+    }
+}
+
+/// Describes a page request for [`Error::context`]. The parameters never
+/// include the credentials.
+fn page_context(endpoint: &str, parameters: &[(String, String)], page: u32) -> String {
+    let query = parameters
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("failed to fetch page {page} of `{endpoint}` (query: {query:?})")
 }
 
 /// Configuration options for the API call. Currently this
@@ -31,10 +228,51 @@ pub struct Configuration {
     pub email: String,
 }
 
-trait AsParameter {
+impl Configuration {
+    /// Read the configuration from the `ACLED_KEY` and `ACLED_EMAIL`
+    /// environment variables, so credentials don't have to be part of the
+    /// source code. Fails with [`Error::MissingEnvironment`] listing every
+    /// variable that is unset or empty.
+    ///
+    /// ```no_run
+    /// use acled_api::{Api, Configuration};
+    /// let api = Api::new(Configuration::from_env().unwrap());
+    /// ```
+    pub fn from_env() -> Result<Configuration, Error> {
+        Configuration::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Configuration, Error> {
+        let mut missing = Vec::new();
+        let mut var = |name: &'static str| {
+            let value = lookup(name).filter(|value| !value.is_empty());
+            if value.is_none() {
+                missing.push(name);
+            }
+            value.unwrap_or_default()
+        };
+        let key = var("ACLED_KEY");
+        let email = var("ACLED_EMAIL");
+
+        if !missing.is_empty() {
+            return Err(Error::MissingEnvironment(missing));
+        }
+        Ok(Configuration { key, email })
+    }
+}
+
+/// Types that can be used as the value of a [`Where`] filter.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait AsParameter: sealed::Sealed {
+    /// The value as it is sent in the query string.
     fn as_parameter(&self) -> String;
 }
 
+mod sealed {
+    pub trait Sealed {}
+}
+
 impl AsParameter for NaiveDate {
     fn as_parameter(&self) -> String {
         self.to_string()
@@ -55,12 +293,64 @@ impl AsParameter for u64 {
         self.to_string()
     }
 }
+impl AsParameter for f64 {
+    fn as_parameter(&self) -> String {
+        self.to_string()
+    }
+}
+impl AsParameter for &str {
+    fn as_parameter(&self) -> String {
+        (*self).to_owned()
+    }
+}
+impl AsParameter for i32 {
+    fn as_parameter(&self) -> String {
+        self.to_string()
+    }
+}
+impl AsParameter for i64 {
+    fn as_parameter(&self) -> String {
+        self.to_string()
+    }
+}
+/// Formatted as a unix timestamp, like the `timestamp` field.
+impl AsParameter for DateTime<Utc> {
+    fn as_parameter(&self) -> String {
+        self.timestamp().to_string()
+    }
+}
+/// Formatted as a unix timestamp, like the `timestamp` field. The value is
+/// assumed to be in UTC.
+impl AsParameter for NaiveDateTime {
+    fn as_parameter(&self) -> String {
+        self.and_utc().timestamp().to_string()
+    }
+}
+
+/// Convert a unix timestamp of the API, saturating at the latest
+/// representable date.
+fn timestamp_to_datetime(timestamp: u64) -> DateTime<Utc> {
+    i64::try_from(timestamp)
+        .ok()
+        .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+impl sealed::Sealed for NaiveDate {}
+impl sealed::Sealed for String {}
+impl sealed::Sealed for u32 {}
+impl sealed::Sealed for u64 {}
+impl sealed::Sealed for f64 {}
+impl sealed::Sealed for &str {}
+impl sealed::Sealed for i32 {}
+impl sealed::Sealed for i64 {}
+impl sealed::Sealed for DateTime<Utc> {}
+impl sealed::Sealed for NaiveDateTime {}
 
 /// This enum is used to specify the filter options for a specific
 /// parameter in a query.
 ///
 /// See also <https://apidocs.acleddata.com/generalities_section.html#query-types>
-#[allow(private_bounds)]
 pub enum Where<T: AsParameter> {
     /// This default options means the query should not use this parameter
     /// at all; i.e., it's not added to the query string.
@@ -73,6 +363,11 @@ pub enum Where<T: AsParameter> {
     /// Look for values like the value. Can use `*` to match the wildcard.
     /// (Query type `LIKE`)
     Like(T),
+    /// Doesn't exactly match the specific parameter. (Query type `!=`)
+    NotEqual(T),
+    /// Look for values not like the value. Can use `*` to match the wildcard.
+    /// (Query type `NOT LIKE`)
+    NotLike(T),
     /// Numeric/date value is greater than.
     /// (Query type `>`)
     GreaterThan(T),
@@ -80,15 +375,40 @@ pub enum Where<T: AsParameter> {
     /// (undocumented query type `>=`)
     GreaterThanOrEqual(T),
     Between(T, T),
+    /// Matches any of the values, joined with `:OR:`. An empty list is
+    /// ignored like [`Where::Unspecified`].
+    In(Vec<T>),
 }
 
+#[allow(clippy::derivable_impls)]
 impl<T: AsParameter> Default for Where<T> {
     fn default() -> Self {
         Self::Unspecified
     }
 }
 
-#[allow(private_bounds)]
+/// `start..=end` is the same as [`Where::Between`].
+impl<T: AsParameter> From<RangeInclusive<T>> for Where<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::Between(start, end)
+    }
+}
+
+/// `start..` is the same as [`Where::GreaterThanOrEqual`].
+impl<T: AsParameter> From<RangeFrom<T>> for Where<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        Self::GreaterThanOrEqual(range.start)
+    }
+}
+
+/// `start..end` is the same as [`Where::Between`] with the day before `end`.
+impl From<Range<NaiveDate>> for Where<NaiveDate> {
+    fn from(range: Range<NaiveDate>) -> Self {
+        Self::Between(range.start, range.end.pred_opt().unwrap_or(range.end))
+    }
+}
+
 impl<T: AsParameter> Where<T> {
     fn as_parameters(&self, name: &str) -> Vec<(String, String)> {
         match self {
@@ -102,6 +422,14 @@ impl<T: AsParameter> Where<T> {
                 (format!("{name}_where"), "LIKE".to_owned()),
                 (name.to_owned(), v.as_parameter()),
             ],
+            Self::NotEqual(v) => vec![
+                (format!("{name}_where"), "!=".to_owned()),
+                (name.to_owned(), v.as_parameter()),
+            ],
+            Self::NotLike(v) => vec![
+                (format!("{name}_where"), "NOT LIKE".to_owned()),
+                (name.to_owned(), v.as_parameter()),
+            ],
             Self::GreaterThan(v) => vec![
                 (format!("{name}_where"), ">".to_owned()),
                 (name.to_owned(), v.as_parameter()),
@@ -117,13 +445,72 @@ impl<T: AsParameter> Where<T> {
                     format!("{}|{}", a.as_parameter(), b.as_parameter()),
                 ),
             ],
+            Self::In(values) => {
+                if values.is_empty() {
+                    return Vec::new();
+                }
+                // Note: The API expects e.g. `country=Mali:OR:country=Niger`.
+                let value = values
+                    .iter()
+                    .map(AsParameter::as_parameter)
+                    .collect::<Vec<_>>()
+                    .join(&format!(":OR:{name}="));
+                vec![(name.to_owned(), value)]
+            }
         }
     }
 }
 
 /// The default row limit of the ACLED API is 5000.
 /// See <https://apidocs.acleddata.com/generalities_section.html#adjusting-the-limit-on-the-number-of-rows-returned>
-static DEFAULT_LIMIT: usize = 5000;
+static DEFAULT_LIMIT: u32 = 5000;
+
+/// A limit of 0 asks the API to return all rows at once, so there is
+/// never a second page in that case.
+fn is_last_page(rows: usize, limit: u32) -> bool {
+    limit == 0 || rows != limit as usize
+}
+
+/// Called with the raw response body and progress after every page.
+type OnPage<'a> = dyn FnMut(&[u8], Progress) -> Result<(), Error> + 'a;
+
+fn parse_page<D, E>(body: &[u8], limit: Option<u32>, page: u32) -> Result<Page<E>, Error>
+where
+    D: DeserializeOwned,
+    E: TryFrom<D, Error = Error>,
+{
+    let response = serde_json::from_slice::<Response<D>>(body)?;
+    let events = response.into::<E>()?;
+
+    let count = events.len();
+    // Note: For some strange reason, the API doesn't explicitly
+    // indicate that we have to request another page.
+    let has_more = !is_last_page(count, limit.unwrap_or(DEFAULT_LIMIT));
+    Ok(Page {
+        events,
+        count,
+        page,
+        has_more,
+    })
+}
+
+fn raw_parameters(parameters: &[(&str, &str)]) -> Vec<(String, String)> {
+    parameters
+        .iter()
+        .map(|(name, value)| ((*name).to_owned(), (*value).to_owned()))
+        .collect()
+}
+
+/// A row returned by [`Api::get_raw_all`].
+struct RawRow(serde_json::Value);
+
+impl TryFrom<serde_json::Value> for RawRow {
+    type Error = Error;
+
+    fn try_from(row: serde_json::Value) -> Result<Self, Self::Error> {
+        Ok(RawRow(row))
+    }
+}
 
 /// The main entry point that can be used to query the different endpoints
 /// provided by ACLED.
@@ -139,82 +526,1154 @@ static DEFAULT_LIMIT: usize = 5000;
 /// let api = Api::new(configuration);
 /// ```
 pub struct Api {
-    config: Configuration,
-    base: String,
+    auth: Auth,
+    transport: Box<dyn HttpTransport>,
+    headers: Vec<(String, String)>,
+    base: BaseUrls,
+    retries: u32,
+    throttle: Option<Throttle>,
 }
 
 impl Api {
     // Initially inspired by https://crates.io/crates/fastly-api
 
+    #[cfg(feature = "reqwest")]
     pub fn new(config: Configuration) -> Api {
-        let base = "https://api.acleddata.com".to_owned();
-        Api { config, base }
+        Api::with_credentials(Credentials::Key(config))
+    }
+
+    /// Like [`Api::new`], but for any kind of [`Credentials`], e.g. the
+    /// OAuth tokens of the new API:
+    ///
+    /// ```
+    /// use acled_api::{Api, Credentials};
+    /// let api = Api::with_credentials(Credentials::OAuth {
+    ///   username: "foo@example.com".into(),
+    ///   password: "XXXXX".into(),
+    /// });
+    /// ```
+    #[cfg(feature = "reqwest")]
+    pub fn with_credentials(credentials: Credentials) -> Api {
+        let base = BaseUrls::new(vec![credentials.base().to_owned()])
+            .expect("the default base URLs are valid");
+        Api {
+            auth: Auth::new(credentials),
+            transport: Box::new(ReqwestTransport::default()),
+            headers: api_builder::default_headers(),
+            base,
+            retries: 0,
+            throttle: None,
+        }
+    }
+
+    /// Send requests to `base_url` instead of the official API, e.g. to a
+    /// caching proxy, a mirror or a mock server in tests. Endpoints are
+    /// appended to it, e.g. `{base_url}/acled/read`.
+    /// With [`Credentials::OAuth`], tokens are requested from the same
+    /// server: `{base_url}/oauth/token`, without a trailing `/api`.
+    ///
+    /// Fails with [`Error::InvalidConfiguration`] unless `base_url` is an
+    /// absolute `http` or `https` URL.
+    ///
+    /// ```
+    /// use acled_api::{Api, Configuration};
+    /// # let configuration = Configuration { key: "XXXXX".into(), email: "foo@example.com".into() };
+    /// let api = Api::new(configuration).with_base_url("http://localhost:8080")?;
+    /// # Ok::<(), acled_api::Error>(())
+    /// ```
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Result<Api, Error> {
+        self.with_base_urls([base_url])
+    }
+
+    /// Like [`Api::with_base_url`], but with fallbacks: requests go to the
+    /// first URL until it fails three times in a row (connection errors or
+    /// HTTP 5xx), then to the next one. The first URL is probed again every
+    /// five minutes and used again as soon as it responds. See
+    /// [`Api::with_failover`] to change these defaults.
+    ///
+    /// Failover happens between attempts, so combine this with
+    /// [`Api::with_retries`].
+    ///
+    /// Fails with [`Error::InvalidConfiguration`] if `base_urls` is empty or
+    /// one of them isn't an absolute `http` or `https` URL.
+    ///
+    /// ```
+    /// use acled_api::{Api, Configuration};
+    /// # let configuration = Configuration { key: "XXXXX".into(), email: "foo@example.com".into() };
+    /// let api = Api::new(configuration)
+    ///   .with_base_urls(["https://api.acleddata.com", "http://acled-mirror.internal"])?
+    ///   .with_retries(5);
+    /// # Ok::<(), acled_api::Error>(())
+    /// ```
+    pub fn with_base_urls<S: Into<String>>(
+        mut self,
+        base_urls: impl IntoIterator<Item = S>,
+    ) -> Result<Api, Error> {
+        self.base = BaseUrls::new(base_urls.into_iter().map(Into::into).collect())?;
+        Ok(self)
+    }
+
+    /// Fail over to the next base URL after `threshold` consecutive failures,
+    /// and probe the first one again every `probe_interval`. See
+    /// [`Api::with_base_urls`].
+    pub fn with_failover(mut self, threshold: u32, probe_interval: std::time::Duration) -> Api {
+        self.base = self.base.with_failover(threshold, probe_interval);
+        self
+    }
+
+    /// Retry failed requests up to `retries` times in total per operation,
+    /// e.g. per call to [`Api::get_acled`], with exponential backoff.
+    /// Only transient errors are retried, see [`Error::is_transient`].
+    ///
+    /// By default requests are not retried.
+    pub fn with_retries(mut self, retries: u32) -> Api {
+        self.retries = retries;
+        self
+    }
+
+    /// Limit the download bandwidth to `bytes_per_second`. The limit applies
+    /// to all requests made by this `Api` combined, including concurrent ones.
+    ///
+    /// By default the bandwidth isn't limited.
+    pub fn with_max_bandwidth(mut self, bytes_per_second: u64) -> Api {
+        self.throttle = Some(Throttle::new(bytes_per_second));
+        self
     }
 
     /// Query the `acled` endpoint for events.
     ///
     /// See also <https://apidocs.acleddata.com/acled_endpoint.html>.
     pub fn get_acled(&self, query: &AcledQuery) -> Result<Vec<AcledEvent>, Error> {
+        let (events, _) = self.get_acled_with_stats(query)?;
+        Ok(events)
+    }
+
+    /// Like [`Api::get_acled`], but for queries that only select some
+    /// columns with [`AcledQuery::fields`].
+    pub fn get_acled_sparse(&self, query: &AcledQuery) -> Result<Vec<SparseEvent>, Error> {
         let parameters = query.as_parameters();
+        let (events, _) = self.get_all::<SparseData, _>(
+            "acled",
+            &parameters,
+            query.paging(),
+            &mut |_, _| Ok(()),
+        )?;
+        Ok(events)
+    }
 
-        let mut all_events = Vec::new();
-        for page in 1.. {
-            let response = self
-                .query("acled", &parameters, page)?
-                .json::<Response<AcledData>>()?;
-            let events = response.into::<AcledEvent>()?;
-
-            all_events.extend_from_slice(&events);
-            // Note: For some strange reason, the API doesn't explicitly
-            // indicate that we have to request another page.
-            if events.len() != DEFAULT_LIMIT {
-                return Ok(all_events);
-            }
-        }
+    /// Like [`Api::get_acled`], but only requests the columns of the
+    /// [`Projection`] `P`, e.g. [`MinimalEvent`]. Any columns selected with
+    /// [`AcledQuery::fields`] are replaced.
+    pub fn get_acled_projected<P: Projection>(&self, query: &AcledQuery) -> Result<Vec<P>, Error> {
+        let mut parameters = query.as_parameters();
+        parameters.retain(|(name, _)| name != "fields");
+        let fields = P::FIELDS
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("|");
+        parameters.push(("fields".into(), fields));
 
-        unreachable!()
+        let (events, _) = self.get_all::<Columns, Projected<P>>(
+            "acled",
+            &parameters,
+            query.paging(),
+            &mut |_, _| Ok(()),
+        )?;
+        Ok(events.into_iter().map(|Projected(event)| event).collect())
+    }
+
+    /// Like [`Api::get_acled`], but returns [`EventRecord`]s with whatever
+    /// columns were selected with [`AcledQuery::fields`].
+    pub fn get_acled_records(&self, query: &AcledQuery) -> Result<Vec<EventRecord>, Error> {
+        let parameters = query.as_parameters();
+        let (events, _) = self.get_all::<Columns, EventRecord>(
+            "acled",
+            &parameters,
+            query.paging(),
+            &mut |_, _| Ok(()),
+        )?;
+        Ok(events)
+    }
+
+    /// Like [`Api::get_acled`], but also returns statistics about the
+    /// requests that were made.
+    pub fn get_acled_with_stats(
+        &self,
+        query: &AcledQuery,
+    ) -> Result<(Vec<AcledEvent>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all::<AcledData, _>("acled", &parameters, query.paging(), &mut |_, _| Ok(()))
+    }
+
+    /// Like [`Api::get_acled`], but calls `on_progress` after every page.
+    ///
+    /// ```no_run
+    /// use acled_api::{AcledQuery, Api, Configuration, Where};
+    /// # let api = Api::new(Configuration { key: "XXXXX".into(), email: "foo@example.com".into() });
+    /// let query = AcledQuery {
+    ///   country: Where::Matches("Mali".into()),
+    ///   ..Default::default()
+    /// };
+    /// let events = api.get_acled_with_progress(&query, |progress| {
+    ///   println!("{} events after {:?}", progress.events, progress.elapsed);
+    /// });
+    /// ```
+    pub fn get_acled_with_progress(
+        &self,
+        query: &AcledQuery,
+        mut on_progress: impl FnMut(Progress),
+    ) -> Result<Vec<AcledEvent>, Error> {
+        let parameters = query.as_parameters();
+        let (events, _) = self.get_all::<AcledData, _>(
+            "acled",
+            &parameters,
+            query.paging(),
+            &mut |_, progress| {
+                on_progress(progress);
+                Ok(())
+            },
+        )?;
+        Ok(events)
+    }
+
+    /// Like [`Api::get_acled`], but stores every raw page in the `archive`
+    /// and returns a [`Manifest`] describing the pull.
+    pub fn get_acled_archived(
+        &self,
+        query: &AcledQuery,
+        archive: &Archive,
+    ) -> Result<(Vec<AcledEvent>, Manifest), Error> {
+        let parameters = query.as_parameters();
+        self.get_all_archived::<AcledData, _>("acled", &parameters, query.paging(), archive)
+    }
+
+    /// Like [`Api::get_acled`], but fetches up to `parallelism` pages at the
+    /// same time.
+    ///
+    /// Because the API doesn't report the total number of events, pages are
+    /// requested in batches until a batch contains the last page. This means
+    /// up to `parallelism - 1` requests at the end can be wasted.
+    pub fn get_acled_concurrent(
+        &self,
+        query: &AcledQuery,
+        parallelism: usize,
+    ) -> Result<Vec<AcledEvent>, Error> {
+        let (events, _) = self.get_acled_concurrent_with_stats(query, parallelism)?;
+        Ok(events)
+    }
+
+    /// Like [`Api::get_acled_concurrent`], but also returns statistics about
+    /// the requests that were made, including the wasted ones.
+    pub fn get_acled_concurrent_with_stats(
+        &self,
+        query: &AcledQuery,
+        parallelism: usize,
+    ) -> Result<(Vec<AcledEvent>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all_concurrent::<AcledData, _>("acled", &parameters, query.paging(), parallelism)
+    }
+
+    /// Query a single page of the `acled` endpoint. Pages start at 1.
+    ///
+    /// This can be used to control pagination manually, e.g. to resume
+    /// a long running download.
+    pub fn get_acled_page(&self, query: &AcledQuery, page: u32) -> Result<Page<AcledEvent>, Error> {
+        let parameters = query.as_parameters();
+        let mut stats = FetchStats::default();
+        let budget = RetryBudget::new(self.retries);
+        self.get_page::<AcledData, _>("acled", &parameters, query.limit, page, &mut stats, &budget)
     }
 
     /// Query the `deleted` endpoint for (deleted) events.
     ///
     /// See also <https://apidocs.acleddata.com/deleted_endpoint.html>.
     pub fn get_deleted(&self, query: &DeletedQuery) -> Result<Vec<DeletedEvent>, Error> {
+        let (events, _) = self.get_deleted_with_stats(query)?;
+        Ok(events)
+    }
+
+    /// Like [`Api::get_deleted`], but also returns statistics about the
+    /// requests that were made.
+    pub fn get_deleted_with_stats(
+        &self,
+        query: &DeletedQuery,
+    ) -> Result<(Vec<DeletedEvent>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all::<DeletedData, _>("deleted", &parameters, query.paging(), &mut |_, _| Ok(()))
+    }
+
+    /// Like [`Api::get_deleted`], but calls `on_progress` after every page.
+    pub fn get_deleted_with_progress(
+        &self,
+        query: &DeletedQuery,
+        mut on_progress: impl FnMut(Progress),
+    ) -> Result<Vec<DeletedEvent>, Error> {
         let parameters = query.as_parameters();
+        let (events, _) = self.get_all::<DeletedData, _>(
+            "deleted",
+            &parameters,
+            query.paging(),
+            &mut |_, progress| {
+                on_progress(progress);
+                Ok(())
+            },
+        )?;
+        Ok(events)
+    }
+
+    /// Like [`Api::get_deleted`], but stores every raw page in the `archive`
+    /// and returns a [`Manifest`] describing the pull.
+    pub fn get_deleted_archived(
+        &self,
+        query: &DeletedQuery,
+        archive: &Archive,
+    ) -> Result<(Vec<DeletedEvent>, Manifest), Error> {
+        let parameters = query.as_parameters();
+        self.get_all_archived::<DeletedData, _>("deleted", &parameters, query.paging(), archive)
+    }
+
+    /// Like [`Api::get_deleted`], but fetches up to `parallelism` pages at the
+    /// same time. See [`Api::get_acled_concurrent`].
+    pub fn get_deleted_concurrent(
+        &self,
+        query: &DeletedQuery,
+        parallelism: usize,
+    ) -> Result<Vec<DeletedEvent>, Error> {
+        let (events, _) = self.get_deleted_concurrent_with_stats(query, parallelism)?;
+        Ok(events)
+    }
+
+    /// Like [`Api::get_deleted_concurrent`], but also returns statistics
+    /// about the requests that were made, including the wasted ones.
+    pub fn get_deleted_concurrent_with_stats(
+        &self,
+        query: &DeletedQuery,
+        parallelism: usize,
+    ) -> Result<(Vec<DeletedEvent>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all_concurrent::<DeletedData, _>(
+            "deleted",
+            &parameters,
+            query.paging(),
+            parallelism,
+        )
+    }
+
+    /// Query a single page of the `deleted` endpoint. Pages start at 1.
+    pub fn get_deleted_page(
+        &self,
+        query: &DeletedQuery,
+        page: u32,
+    ) -> Result<Page<DeletedEvent>, Error> {
+        let parameters = query.as_parameters();
+        let mut stats = FetchStats::default();
+        let budget = RetryBudget::new(self.retries);
+        self.get_page::<DeletedData, _>(
+            "deleted",
+            &parameters,
+            query.limit,
+            page,
+            &mut stats,
+            &budget,
+        )
+    }
+
+    /// Query the `actor` endpoint for actors and their number of events.
+    ///
+    /// See also <https://apidocs.acleddata.com/actor_endpoint.html>.
+    pub fn get_actor(&self, query: &ActorQuery) -> Result<Vec<Actor>, Error> {
+        let (actors, _) = self.get_actor_with_stats(query)?;
+        Ok(actors)
+    }
+
+    /// Like [`Api::get_actor`], but also returns statistics about the
+    /// requests that were made.
+    pub fn get_actor_with_stats(
+        &self,
+        query: &ActorQuery,
+    ) -> Result<(Vec<Actor>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all::<ActorData, _>("actor", &parameters, query.paging(), &mut |_, _| Ok(()))
+    }
+
+    /// Query the `actortype` endpoint for the actor categories and their
+    /// number of events.
+    ///
+    /// See also <https://apidocs.acleddata.com/actortype_endpoint.html>.
+    pub fn get_actor_types(&self, query: &ActorTypeQuery) -> Result<Vec<ActorType>, Error> {
+        let (actor_types, _) = self.get_actor_types_with_stats(query)?;
+        Ok(actor_types)
+    }
+
+    /// Like [`Api::get_actor_types`], but also returns statistics about the
+    /// requests that were made.
+    pub fn get_actor_types_with_stats(
+        &self,
+        query: &ActorTypeQuery,
+    ) -> Result<(Vec<ActorType>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all::<ActorTypeData, _>("actortype", &parameters, query.paging(), &mut |_, _| {
+            Ok(())
+        })
+    }
+
+    /// Query the `country` endpoint for countries and their number of events.
+    ///
+    /// See also <https://apidocs.acleddata.com/country_endpoint.html>.
+    pub fn get_countries(&self, query: &CountryQuery) -> Result<Vec<Country>, Error> {
+        let (countries, _) = self.get_countries_with_stats(query)?;
+        Ok(countries)
+    }
+
+    /// Like [`Api::get_countries`], but also returns statistics about the
+    /// requests that were made.
+    pub fn get_countries_with_stats(
+        &self,
+        query: &CountryQuery,
+    ) -> Result<(Vec<Country>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all::<CountryData, _>("country", &parameters, query.paging(), &mut |_, _| Ok(()))
+    }
+
+    /// Query the `region` endpoint for regions and their number of events.
+    /// Use [`RegionInfo::is_consistent`] to detect regions that [`Region`]
+    /// doesn't know about.
+    ///
+    /// See also <https://apidocs.acleddata.com/region_endpoint.html>.
+    pub fn get_regions(&self, query: &RegionQuery) -> Result<Vec<RegionInfo>, Error> {
+        let (regions, _) = self.get_regions_with_stats(query)?;
+        Ok(regions)
+    }
+
+    /// Like [`Api::get_regions`], but also returns statistics about the
+    /// requests that were made.
+    pub fn get_regions_with_stats(
+        &self,
+        query: &RegionQuery,
+    ) -> Result<(Vec<RegionInfo>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all::<RegionData, _>("region", &parameters, query.paging(), &mut |_, _| Ok(()))
+    }
+
+    /// Query the `cast` endpoint for conflict forecasts.
+    ///
+    /// See also <https://apidocs.acleddata.com/cast_endpoint.html>.
+    pub fn get_cast(&self, query: &CastQuery) -> Result<Vec<CastForecast>, Error> {
+        let (forecasts, _) = self.get_cast_with_stats(query)?;
+        Ok(forecasts)
+    }
+
+    /// Like [`Api::get_cast`], but also returns statistics about the
+    /// requests that were made.
+    pub fn get_cast_with_stats(
+        &self,
+        query: &CastQuery,
+    ) -> Result<(Vec<CastForecast>, FetchStats), Error> {
+        let parameters = query.as_parameters();
+        self.get_all::<CastData, _>("cast", &parameters, query.paging(), &mut |_, _| Ok(()))
+    }
+
+    /// Query a custom [`Endpoint`], e.g. one that this crate doesn't
+    /// support yet.
+    pub fn get<E: Endpoint>(&self, query: &E::Query) -> Result<Vec<E::Item>, Error> {
+        let parameters = E::parameters(query);
+        let (items, _) = self.get_all::<Row<E>, Item<E>>(
+            E::PATH,
+            &parameters,
+            E::paging(query),
+            &mut |_, _| Ok(()),
+        )?;
+        Ok(items.into_iter().map(|Item(item)| item).collect())
+    }
+
+    /// Send a single request to any endpoint and return the response as
+    /// JSON, e.g. for parameters or columns that the typed queries don't
+    /// support yet. Error responses are still returned as
+    /// [`Error::APIError`]. See also [`Api::get_raw_all`].
+    pub fn get_raw(
+        &self,
+        endpoint: &str,
+        parameters: &[(&str, &str)],
+    ) -> Result<serde_json::Value, Error> {
+        let parameters = raw_parameters(parameters);
+        let budget = RetryBudget::new(self.retries);
+        let body = self
+            .get_body(
+                endpoint,
+                &parameters,
+                1,
+                &mut FetchStats::default(),
+                &budget,
+            )
+            .map_err(|error| error.context(page_context(endpoint, &parameters, 1)))?;
+
+        let response = serde_json::from_slice::<serde_json::Value>(&body)?;
+        if response["success"] == false {
+            return Err(Error::APIError {
+                message: response["error"]["message"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_owned(),
+            });
+        }
+        Ok(response)
+    }
+
+    /// Like [`Api::get_raw`], but fetches all pages and only returns the
+    /// rows. Use the `limit` parameter to change the page size.
+    pub fn get_raw_all(
+        &self,
+        endpoint: &str,
+        parameters: &[(&str, &str)],
+    ) -> Result<Vec<serde_json::Value>, Error> {
+        let parameters = raw_parameters(parameters);
+        let limit = parameters
+            .iter()
+            .find(|(name, _)| name == "limit")
+            .and_then(|(_, limit)| limit.parse().ok());
+        let paging = Paging {
+            limit,
+            ..Default::default()
+        };
+        let (rows, _) = self.get_all::<serde_json::Value, RawRow>(
+            endpoint,
+            &parameters,
+            paging,
+            &mut |_, _| Ok(()),
+        )?;
+        Ok(rows.into_iter().map(|RawRow(row)| row).collect())
+    }
+
+    /// Fetch up to `n` raw rows from any endpoint, e.g. `"acled"` or
+    /// `"cast"`, without a typed query. This is useful for discovering the
+    /// columns an endpoint returns.
+    pub fn sample(&self, endpoint: &str, n: u32) -> Result<Vec<serde_json::Value>, Error> {
+        // Note: The API treats a limit of 0 as "no limit".
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let parameters = vec![("limit".to_owned(), n.to_string())];
+        let paging = Paging {
+            limit: Some(n),
+            max_pages: Some(1),
+            ..Default::default()
+        };
+        let (rows, _) = self.get_all::<serde_json::Value, RawRow>(
+            endpoint,
+            &parameters,
+            paging,
+            &mut |_, _| Ok(()),
+        )?;
+        Ok(rows.into_iter().map(|RawRow(row)| row).collect())
+    }
+
+    /// Fetch a single row from the `acled` endpoint and compare its columns
+    /// against the ones this crate expects. This is useful as an early warning
+    /// for upstream schema changes.
+    pub fn diagnose(&self) -> Result<SchemaReport, Error> {
+        let rows = self.sample("acled", 1)?;
+        let row = rows
+            .first()
+            .and_then(serde_json::Value::as_object)
+            .ok_or_else(|| Error::ParseError("no rows returned".into()))?;
+        Ok(SchemaReport::from_row(row))
+    }
+
+    fn get_all_archived<D, E>(
+        &self,
+        endpoint: &str,
+        parameters: &[(String, String)],
+        paging: Paging,
+        archive: &Archive,
+    ) -> Result<(Vec<E>, Manifest), Error>
+    where
+        D: DeserializeOwned,
+        E: TryFrom<D, Error = Error>,
+    {
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        let mut pages = Vec::new();
+        let (events, _) = self.get_all::<D, E>(endpoint, parameters, paging, &mut |body, _| {
+            pages.push(archive.store_page(body)?);
+            Ok(())
+        })?;
+
+        let manifest = Manifest {
+            endpoint: endpoint.to_owned(),
+            parameters: parameters.to_vec(),
+            fetched_at,
+            key_fingerprint: archive::key_fingerprint(self.auth.credentials().account()),
+            crate_version: env!("CARGO_PKG_VERSION").to_owned(),
+            max_results: paging.max_results,
+            pages,
+        };
+        Ok((events, manifest))
+    }
+
+    /// Fetch all pages. `on_page` is called with the raw response body of
+    /// every page and the progress so far.
+    fn get_all<D, E>(
+        &self,
+        endpoint: &str,
+        parameters: &[(String, String)],
+        paging: Paging,
+        on_page: &mut OnPage,
+    ) -> Result<(Vec<E>, FetchStats), Error>
+    where
+        D: DeserializeOwned,
+        E: TryFrom<D, Error = Error>,
+    {
+        let start = Instant::now();
+        let mut stats = FetchStats::default();
+        let budget = RetryBudget::new(self.retries);
 
         let mut all_events = Vec::new();
         for page in 1.. {
-            let response = self
-                .query("deleted", &parameters, page)?
-                .json::<Response<DeletedData>>()?;
-            let events = response.into::<DeletedEvent>()?;
-
-            all_events.extend_from_slice(&events);
-            // Note: For some strange reason, the API doesn't explicitly
-            // indicate that we have to request another page.
-            if events.len() != DEFAULT_LIMIT {
-                return Ok(all_events);
+            let (body, parsed) = self
+                .get_body(endpoint, parameters, page, &mut stats, &budget)
+                .and_then(|body| {
+                    let parsed = parse_page::<D, E>(&body, paging.limit, page)?;
+                    Ok((body, parsed))
+                })
+                .map_err(|error| error.context(page_context(endpoint, parameters, page)))?;
+            let Page {
+                events, has_more, ..
+            } = parsed;
+            stats.duration = start.elapsed();
+
+            all_events.extend(events);
+            let mut done = !has_more || paging.max_pages.is_some_and(|max_pages| page >= max_pages);
+            if let Some(max_results) = paging.max_results {
+                if all_events.len() >= max_results {
+                    all_events.truncate(max_results);
+                    done = true;
+                }
+            }
+
+            on_page(
+                &body,
+                Progress {
+                    pages: page,
+                    events: all_events.len(),
+                    elapsed: stats.duration,
+                },
+            )?;
+            if done {
+                return Ok((all_events, stats));
             }
         }
 
         unreachable!()
     }
 
+    fn get_all_concurrent<D, E>(
+        &self,
+        endpoint: &str,
+        parameters: &[(String, String)],
+        paging: Paging,
+        parallelism: usize,
+    ) -> Result<(Vec<E>, FetchStats), Error>
+    where
+        D: DeserializeOwned,
+        E: TryFrom<D, Error = Error> + Send,
+    {
+        let parallelism = u32::try_from(parallelism.max(1)).unwrap_or(u32::MAX);
+        let start = Instant::now();
+        let mut stats = FetchStats::default();
+        let budget = RetryBudget::new(self.retries);
+
+        let mut all_events = Vec::new();
+        let mut first: u32 = 1;
+        loop {
+            let mut last = first.saturating_add(parallelism - 1);
+            if let Some(max_pages) = paging.max_pages {
+                last = last.min(max_pages.max(1));
+            }
+
+            let pages = std::thread::scope(|scope| {
+                let handles: Vec<_> = (first..=last)
+                    .map(|page| {
+                        let budget = &budget;
+                        scope.spawn(move || {
+                            let mut stats = FetchStats::default();
+                            let page = self.get_page::<D, E>(
+                                endpoint,
+                                parameters,
+                                paging.limit,
+                                page,
+                                &mut stats,
+                                budget,
+                            );
+                            (page, stats)
+                        })
+                    })
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        let (page, page_stats) =
+                            handle.join().expect("fetching a page should not panic");
+                        stats.add(&page_stats);
+                        page
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            })?;
+            stats.duration = start.elapsed();
+
+            // Note: The pages are joined in order, so everything after the
+            // first page that isn't full can be discarded.
+            for Page {
+                events,
+                has_more,
+                page,
+                ..
+            } in pages
+            {
+                all_events.extend(events);
+                if let Some(max_results) = paging.max_results {
+                    if all_events.len() >= max_results {
+                        all_events.truncate(max_results);
+                        return Ok((all_events, stats));
+                    }
+                }
+                if !has_more || paging.max_pages.is_some_and(|max_pages| page >= max_pages) {
+                    return Ok((all_events, stats));
+                }
+            }
+
+            first = last + 1;
+        }
+    }
+
+    fn get_page<D, E>(
+        &self,
+        endpoint: &str,
+        parameters: &[(String, String)],
+        limit: Option<u32>,
+        page: u32,
+        stats: &mut FetchStats,
+        budget: &RetryBudget,
+    ) -> Result<Page<E>, Error>
+    where
+        D: DeserializeOwned,
+        E: TryFrom<D, Error = Error>,
+    {
+        self.get_body(endpoint, parameters, page, stats, budget)
+            .and_then(|body| parse_page::<D, E>(&body, limit, page))
+            .map_err(|error| error.context(page_context(endpoint, parameters, page)))
+    }
+
+    fn get_body(
+        &self,
+        endpoint: &str,
+        parameters: &[(String, String)],
+        page: u32,
+        stats: &mut FetchStats,
+        budget: &RetryBudget,
+    ) -> Result<Vec<u8>, Error> {
+        loop {
+            let result =
+                self.query(endpoint, parameters, page)
+                    .and_then(|mut response| match &self.throttle {
+                        Some(throttle) => Ok(throttle.read_to_end(response.body)?),
+                        None => {
+                            let mut body = Vec::new();
+                            response.body.read_to_end(&mut body)?;
+                            Ok(body)
+                        }
+                    });
+            stats.requests += 1;
+
+            match result {
+                Ok(body) => {
+                    stats.pages += 1;
+                    stats.bytes_downloaded += body.len() as u64;
+                    return Ok(body);
+                }
+                Err(error) if !error.is_transient() => return Err(error),
+                Err(error) => match budget.take() {
+                    Some(delay) => {
+                        stats.retries += 1;
+                        std::thread::sleep(delay);
+                    }
+                    None => return Err(error),
+                },
+            }
+        }
+    }
+
     fn query(
         &self,
         endpoint: &str,
         parameters: &[(String, String)],
         page: u32,
-    ) -> reqwest::Result<reqwest::blocking::Response> {
+    ) -> Result<HttpResponse, Error> {
         let mut params = parameters.to_vec();
-        params.push(("key".into(), self.config.key.clone()));
-        params.push(("email".into(), self.config.email.clone()));
+        params.extend(self.auth.parameters());
         if page > 1 {
             params.push(("page".into(), page.to_string()))
         }
 
-        let url = format!("{}/{endpoint}/read", self.base);
-        let url_with_query =
-            Url::parse_with_params(&url, &params).expect("URL parsing should never fail");
-        reqwest::blocking::get(url_with_query)
+        let (index, base) = self.base.pick();
+        let url = format!("{base}/{endpoint}/read");
+        let url_with_query = Url::parse_with_params(&url, &params).map_err(|error| {
+            Error::InvalidConfiguration(format!("invalid URL `{url}`: {error}"))
+        })?;
+        let result = self
+            .auth
+            .send(
+                self.transport.as_ref(),
+                base,
+                HttpRequest::get(url_with_query, self.headers.clone()),
+            )
+            .and_then(|response| {
+                // Note: Client errors (HTTP 4xx) still come with a JSON
+                // error message, so only fail on server errors and rate
+                // limiting here.
+                if response.status >= 500 || response.status == 429 {
+                    Err(Error::HttpStatus(response.status))
+                } else {
+                    Ok(response)
+                }
+            });
+        // Note: Only errors that hint at an unavailable server count
+        // towards failing over.
+        let failed = result.as_ref().is_err_and(Error::is_transient);
+        self.base.report(index, !failed);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::tests::MockTransport;
+    use std::sync::Arc;
+
+    #[test]
+    fn base_url_test() {
+        let transport = Arc::new(MockTransport::new(vec![(
+            200,
+            r#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI123","deleted_timestamp":"1710025200"}]}"#,
+        )]));
+        let api = Api::builder()
+            .configuration(Configuration {
+                key: "XXXXX".into(),
+                email: "foo@example.com".into(),
+            })
+            .transport(transport.clone())
+            .build()
+            .unwrap()
+            .with_base_url("http://localhost:8080/")
+            .unwrap();
+        let events = api.get_deleted(&DeletedQuery::default()).unwrap();
+        assert_eq!(events[0].id, "MLI123");
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "http://localhost:8080/deleted/read?key=XXXXX&email=foo%40example.com"
+        );
+        assert!(requests[0]
+            .header("user-agent")
+            .unwrap()
+            .starts_with("acled_api/"));
+    }
+
+    #[test]
+    fn fetch_stats_test() {
+        let page1 = r#"{"success":true,"count":2,"data":[{"event_id_cnty":"MLI1","deleted_timestamp":"1710025200"},{"event_id_cnty":"MLI2","deleted_timestamp":"1710025200"}]}"#;
+        let page2 = r#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI3","deleted_timestamp":"1710025200"}]}"#;
+        let transport = Arc::new(MockTransport::new(vec![
+            (200, page1),
+            (503, "Service Unavailable"),
+            (200, page2),
+        ]));
+        let api = Api::builder()
+            .configuration(Configuration {
+                key: "XXXXX".into(),
+                email: "foo@example.com".into(),
+            })
+            .transport(transport.clone())
+            .retries(1)
+            .build()
+            .unwrap();
+        let query = DeletedQuery {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let (events, stats) = api.get_deleted_with_stats(&query).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(stats.requests, 3);
+        assert_eq!(stats.retries, 1);
+        assert_eq!(stats.pages, 2);
+        assert_eq!(stats.bytes_downloaded, (page1.len() + page2.len()) as u64);
+
+        let requests = transport.requests.lock().unwrap();
+        let pages: Vec<_> = requests
+            .iter()
+            .map(|request| {
+                request
+                    .url
+                    .query_pairs()
+                    .find(|(name, _)| name == "page")
+                    .map(|(_, page)| page.into_owned())
+            })
+            .collect();
+        assert_eq!(pages, vec![None, Some("2".into()), Some("2".into())]);
+    }
+
+    /// Answers every request with the response for its `page` parameter, so
+    /// concurrent requests get deterministic responses.
+    struct PagedTransport {
+        pages: Vec<(u16, String)>,
+        requested: std::sync::Mutex<Vec<u32>>,
+    }
+
+    impl HttpTransport for PagedTransport {
+        fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+            let page: u32 = request
+                .url
+                .query_pairs()
+                .find(|(name, _)| name == "page")
+                .map_or(1, |(_, page)| page.parse().unwrap());
+            self.requested.lock().unwrap().push(page);
+            let (status, body) = self.pages[page as usize - 1].clone();
+            Ok(HttpResponse::from_bytes(status, body))
+        }
+    }
+
+    fn deleted_page(ids: &[&str]) -> (u16, String) {
+        let data: Vec<_> = ids
+            .iter()
+            .map(|id| serde_json::json!({"event_id_cnty": id, "deleted_timestamp": "1710025200"}))
+            .collect();
+        let body = serde_json::json!({"success": true, "count": data.len(), "data": data});
+        (200, body.to_string())
+    }
+
+    #[test]
+    fn concurrent_test() {
+        let transport = Arc::new(PagedTransport {
+            pages: vec![
+                deleted_page(&["MLI1"]),
+                deleted_page(&["MLI2"]),
+                deleted_page(&["MLI3"]),
+                deleted_page(&["MLI4"]),
+                deleted_page(&[]),
+                deleted_page(&["MLI6"]),
+            ],
+            requested: Default::default(),
+        });
+        let api = Api::builder()
+            .configuration(Configuration {
+                key: "XXXXX".into(),
+                email: "foo@example.com".into(),
+            })
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let query = DeletedQuery {
+            limit: Some(1),
+            ..Default::default()
+        };
+
+        let (events, stats) = api.get_deleted_concurrent_with_stats(&query, 3).unwrap();
+        let ids: Vec<_> = events.iter().map(|event| event.id.as_str()).collect();
+        assert_eq!(ids, vec!["MLI1", "MLI2", "MLI3", "MLI4"]);
+        assert_eq!(stats.requests, 6);
+        assert_eq!(stats.pages, 6);
+        assert_eq!(stats.retries, 0);
+        assert_eq!(
+            stats.bytes_downloaded,
+            transport
+                .pages
+                .iter()
+                .map(|(_, body)| body.len() as u64)
+                .sum::<u64>()
+        );
+
+        // The second batch is requested completely, but nothing after it.
+        let mut requested = transport.requested.lock().unwrap().clone();
+        requested.sort();
+        assert_eq!(requested, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn concurrent_error_test() {
+        let api = Api::builder()
+            .configuration(Configuration {
+                key: "XXXXX".into(),
+                email: "foo@example.com".into(),
+            })
+            .transport(PagedTransport {
+                pages: vec![
+                    deleted_page(&["MLI1"]),
+                    (
+                        400,
+                        r#"{"success":false,"count":0,"error":{"message":"Invalid page"}}"#.into(),
+                    ),
+                    deleted_page(&["MLI3"]),
+                ],
+                requested: Default::default(),
+            })
+            .build()
+            .unwrap();
+        let query = DeletedQuery {
+            limit: Some(1),
+            ..Default::default()
+        };
+
+        let error = api.get_deleted_concurrent(&query, 3).err().unwrap();
+        assert!(error
+            .to_string()
+            .starts_with("failed to fetch page 2 of `deleted`"));
+        assert!(
+            matches!(error.root_cause(), Error::APIError { message } if message == "Invalid page")
+        );
+    }
+
+    #[test]
+    fn failover_test() {
+        let transport = Arc::new(MockTransport::new(vec![
+            (503, "Service Unavailable"),
+            (
+                200,
+                r#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI123","deleted_timestamp":"1710025200"}]}"#,
+            ),
+        ]));
+        let api = Api::builder()
+            .configuration(Configuration {
+                key: "XXXXX".into(),
+                email: "foo@example.com".into(),
+            })
+            .transport(transport.clone())
+            .base_urls(["http://primary.example.com", "http://mirror.example.com"])
+            .failover(1, std::time::Duration::from_secs(3600))
+            .retries(1)
+            .build()
+            .unwrap();
+        let (events, stats) = api
+            .get_deleted_with_stats(&DeletedQuery::default())
+            .unwrap();
+        assert_eq!(events[0].id, "MLI123");
+        assert_eq!(stats.retries, 1);
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests[0].url.host_str(), Some("primary.example.com"));
+        assert_eq!(requests[1].url.host_str(), Some("mirror.example.com"));
+    }
+
+    #[test]
+    fn sample_test() {
+        let transport = Arc::new(MockTransport::new(vec![
+            (503, "Service Unavailable"),
+            (
+                200,
+                r#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI123"}]}"#,
+            ),
+            (503, "Service Unavailable"),
+            (503, "Service Unavailable"),
+        ]));
+        let api = Api::builder()
+            .configuration(Configuration {
+                key: "XXXXX".into(),
+                email: "foo@example.com".into(),
+            })
+            .transport(transport.clone())
+            .retries(1)
+            .build()
+            .unwrap();
+        let rows = api.sample("acled", 1).unwrap();
+        assert_eq!(rows, vec![serde_json::json!({"event_id_cnty": "MLI123"})]);
+        assert_eq!(transport.requests.lock().unwrap().len(), 2);
+
+        let error = api.sample("acled", 1).err().unwrap();
+        assert!(matches!(error, Error::Context { .. }));
+        assert!(matches!(error.root_cause(), Error::HttpStatus(503)));
+    }
+
+    #[test]
+    fn client_error_test() {
+        let transport = Arc::new(MockTransport::new(vec![(
+            401,
+            r#"{"success":false,"count":0,"error":{"message":"Invalid key"}}"#,
+        )]));
+        let api = Api::builder()
+            .configuration(Configuration {
+                key: "XXXXX".into(),
+                email: "foo@example.com".into(),
+            })
+            .transport(transport.clone())
+            .retries(3)
+            .build()
+            .unwrap();
+        let error = api.get_deleted(&DeletedQuery::default()).err().unwrap();
+        assert!(
+            matches!(error.root_cause(), Error::APIError { message } if message == "Invalid key")
+        );
+        assert!(!error.is_transient());
+        assert_eq!(transport.requests.lock().unwrap().len(), 1);
+
+        assert!(Error::HttpStatus(429).is_transient());
+        assert!(Error::HttpStatus(503).is_transient());
+        assert!(!Error::HttpStatus(404).is_transient());
+    }
+
+    #[test]
+    fn configuration_from_env_test() {
+        let config = Configuration::from_lookup(|name| match name {
+            "ACLED_KEY" => Some("XXXXX".into()),
+            "ACLED_EMAIL" => Some("foo@example.com".into()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(config.key, "XXXXX");
+        assert_eq!(config.email, "foo@example.com");
+
+        let error = Configuration::from_lookup(|name| match name {
+            "ACLED_KEY" => Some("".into()),
+            _ => None,
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "missing environment variables: ACLED_KEY, ACLED_EMAIL"
+        );
+    }
+
+    #[test]
+    fn parameter_test() {
+        assert_eq!("Mali".as_parameter(), "Mali");
+        assert_eq!((-3i32).as_parameter(), "-3");
+        assert_eq!(1710025200i64.as_parameter(), "1710025200");
+        assert_eq!(12.25f64.as_parameter(), "12.25");
+
+        let datetime = NaiveDate::from_ymd_opt(2024, 3, 10)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(datetime.as_parameter(), "1710028800");
+        assert_eq!(datetime.and_utc().as_parameter(), "1710028800");
+    }
+
+    #[test]
+    fn context_test() {
+        let parameters = vec![("country".to_owned(), "Mali".to_owned())];
+        let error = Error::APIError {
+            message: "Invalid key".into(),
+        }
+        .context(page_context("acled", &parameters, 3));
+        assert_eq!(
+            error.to_string(),
+            "failed to fetch page 3 of `acled` (query: \"country=Mali\"): API returned an error: Invalid key"
+        );
+        assert!(matches!(error.root_cause(), Error::APIError { .. }));
     }
 }
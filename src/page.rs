@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+/// A single page of results, as returned by [`crate::Api::get_acled_page`]
+/// and [`crate::Api::get_deleted_page`].
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    /// The events on this page.
+    pub events: Vec<T>,
+    /// The number of rows the API reported for this page.
+    pub count: usize,
+    /// The (1-based) page number that was requested.
+    pub page: u32,
+    /// Whether requesting the next page will likely return more events.
+    ///
+    /// The API doesn't explicitly indicate this, so we assume there are more
+    /// events when the page is completely filled.
+    pub has_more: bool,
+}
+
+/// Statistics about the requests that were made to fetch a result, as
+/// returned by the `*_with_stats` methods, e.g.
+/// [`crate::Api::get_acled_with_stats`] and
+/// [`crate::Api::get_acled_concurrent_with_stats`]. Other methods don't
+/// report them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FetchStats {
+    /// The number of HTTP requests that were made.
+    pub requests: u32,
+    /// The number of requests that were retried. See [`crate::Api::with_retries`].
+    pub retries: u32,
+    /// The total size of all response bodies.
+    pub bytes_downloaded: u64,
+    /// The time it took to fetch all pages.
+    pub duration: Duration,
+    /// The number of pages that were fetched.
+    pub pages: u32,
+}
+
+impl FetchStats {
+    /// Add the counters of `other`, e.g. of a page fetched concurrently.
+    /// The `duration` is left unchanged.
+    pub(crate) fn add(&mut self, other: &FetchStats) {
+        self.requests += other.requests;
+        self.retries += other.retries;
+        self.bytes_downloaded += other.bytes_downloaded;
+        self.pages += other.pages;
+    }
+}
+
+/// Reported after every page by e.g. [`crate::Api::get_acled_with_progress`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of pages fetched so far.
+    pub pages: u32,
+    /// The number of events received so far.
+    pub events: usize,
+    /// The time since the first request was started.
+    pub elapsed: Duration,
+}
+
+/// Client-side pagination options taken from a query. See
+/// [`crate::Endpoint::paging`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Paging {
+    /// The maximum number of rows returned per page, sent as `limit`.
+    pub limit: Option<u32>,
+    /// Stop fetching once at least this many rows have been received.
+    pub max_results: Option<usize>,
+    /// Stop fetching after this many pages.
+    pub max_pages: Option<u32>,
+}
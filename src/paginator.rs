@@ -0,0 +1,92 @@
+use std::cmp::Ordering;
+
+/// Splits an in-memory result into pages of a fixed size, e.g. for
+/// displaying events in a user interface. Pages start at 1, like the pages
+/// of the API.
+///
+/// ```
+/// use acled_api::Paginator;
+///
+/// let paginator = Paginator::new(vec![5, 3, 8, 1, 9], 2).sorted_by(|a, b| a.cmp(b));
+/// assert_eq!(paginator.total_pages(), 3);
+/// assert_eq!(paginator.page(2), &[5, 8]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Paginator<T> {
+    items: Vec<T>,
+    page_size: usize,
+}
+
+impl<T> Paginator<T> {
+    /// Create a paginator with `page_size` items per page. A page size of 0
+    /// is treated as 1.
+    pub fn new(items: Vec<T>, page_size: usize) -> Paginator<T> {
+        Paginator {
+            items,
+            page_size: page_size.max(1),
+        }
+    }
+
+    /// Sort the items. The sort is stable, so items that compare equal keep
+    /// their original order and pages don't change between requests.
+    pub fn sorted_by(mut self, compare: impl FnMut(&T, &T) -> Ordering) -> Paginator<T> {
+        self.items.sort_by(compare);
+        self
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    pub fn total_items(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn total_pages(&self) -> usize {
+        self.items.len().div_ceil(self.page_size)
+    }
+
+    /// The items on the given (1-based) page. Pages outside of the range
+    /// `1..=total_pages()` are empty.
+    pub fn page(&self, page: usize) -> &[T] {
+        let Some(start) = page
+            .checked_sub(1)
+            .and_then(|index| index.checked_mul(self.page_size))
+        else {
+            return &[];
+        };
+        if start >= self.items.len() {
+            return &[];
+        }
+        let end = (start + self.page_size).min(self.items.len());
+        &self.items[start..end]
+    }
+
+    /// Return all items.
+    pub fn into_inner(self) -> Vec<T> {
+        self.items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paginator_test() {
+        let paginator = Paginator::new(vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e')], 2)
+            .sorted_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(paginator.total_items(), 5);
+        assert_eq!(paginator.total_pages(), 3);
+        assert_eq!(paginator.page(0), &[]);
+        assert_eq!(paginator.page(1), &[(1, 'b'), (1, 'd')]);
+        assert_eq!(paginator.page(2), &[(2, 'a'), (2, 'c')]);
+        assert_eq!(paginator.page(3), &[(3, 'e')]);
+        assert_eq!(paginator.page(4), &[]);
+
+        let empty = Paginator::<u32>::new(Vec::new(), 0);
+        assert_eq!(empty.page_size(), 1);
+        assert_eq!(empty.total_pages(), 0);
+        assert!(empty.page(1).is_empty());
+    }
+}
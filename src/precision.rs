@@ -0,0 +1,94 @@
+use crate::{sealed, AsParameter};
+
+/// How precisely the location of an event is known, used by the
+/// `geo_precision` column.
+/// <https://acleddata.com/knowledge-base/codebook/#geo-precision>
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GeoPrecision {
+    /// The exact location of the event, e.g. a town, is known.
+    Exact = 1,
+    /// The event took place near or in part of a region, and the closest
+    /// town is used.
+    Nearby = 2,
+    /// Only a larger region is mentioned, and its provincial capital or a
+    /// central point is used.
+    Region = 3,
+}
+
+impl GeoPrecision {
+    /// The precision level for a numeric code.
+    pub fn from_code(code: u8) -> Option<GeoPrecision> {
+        Some(match code {
+            1 => Self::Exact,
+            2 => Self::Nearby,
+            3 => Self::Region,
+            _ => return None,
+        })
+    }
+
+    /// The numeric code of this precision level.
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// How precisely the date of an event is known, used by the
+/// `time_precision` column.
+/// <https://acleddata.com/knowledge-base/codebook/#time-precision>
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TimePrecision {
+    /// The exact day of the event is known.
+    Day = 1,
+    /// Only the week is known, and the middle of the week is used.
+    Week = 2,
+    /// Only the month is known, and the middle of the month is used.
+    Month = 3,
+}
+
+impl TimePrecision {
+    /// The precision level for a numeric code.
+    pub fn from_code(code: u8) -> Option<TimePrecision> {
+        Some(match code {
+            1 => Self::Day,
+            2 => Self::Week,
+            3 => Self::Month,
+            _ => return None,
+        })
+    }
+
+    /// The numeric code of this precision level.
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsParameter for GeoPrecision {
+    fn as_parameter(&self) -> String {
+        // Note: The query strings use the numeric code.
+        self.code().to_string()
+    }
+}
+
+impl sealed::Sealed for GeoPrecision {}
+
+impl AsParameter for TimePrecision {
+    fn as_parameter(&self) -> String {
+        self.code().to_string()
+    }
+}
+
+impl sealed::Sealed for TimePrecision {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_test() {
+        assert_eq!(GeoPrecision::from_code(2), Some(GeoPrecision::Nearby));
+        assert_eq!(GeoPrecision::from_code(4), None);
+        assert_eq!(TimePrecision::from_code(3), Some(TimePrecision::Month));
+        assert_eq!(TimePrecision::Week.code(), 2);
+        assert_eq!(GeoPrecision::Region.as_parameter(), "3");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::field::Field;
+use crate::Error;
+use chrono::NaiveDate;
+use std::str::FromStr;
+
+/// The raw columns of a single row, as returned by the API.
+pub type Columns = serde_json::Map<String, serde_json::Value>;
+
+/// A typed subset of the columns of the `acled` endpoint. Only the columns
+/// in [`Projection::FIELDS`] are requested, see
+/// [`crate::Api::get_acled_projected`].
+///
+/// Use [`crate::projection!`] to define custom projections.
+pub trait Projection: Sized {
+    /// The columns that are requested for the projection.
+    const FIELDS: &'static [Field];
+
+    /// Parse the projection from the columns of a single row.
+    fn from_columns(columns: &Columns) -> Result<Self, Error>;
+}
+
+/// Parse a single column with [`FromStr`]. Used by [`crate::projection!`].
+#[doc(hidden)]
+pub fn column<T: FromStr>(columns: &Columns, field: Field) -> Result<T, Error> {
+    parse_column(columns, &field.to_string())
+}
+
+pub(crate) fn parse_column<T: FromStr>(columns: &Columns, name: &str) -> Result<T, Error> {
+    let value = match columns.get(name) {
+        Some(serde_json::Value::String(value)) => value.parse().ok(),
+        // Note: Some columns might be returned as JSON numbers instead of
+        // strings.
+        Some(serde_json::Value::Number(value)) => value.to_string().parse().ok(),
+        _ => None,
+    };
+    value.ok_or_else(|| Error::ParseError(name.into()))
+}
+
+/// Define a struct that implements [`Projection`]. Every field is followed
+/// by the [`Field`] it is parsed from, the field type has to implement
+/// [`FromStr`].
+///
+/// ```
+/// use acled_api::{projection, NaiveDate, Region};
+///
+/// projection! {
+///     /// The location of an event.
+///     pub struct Location {
+///         pub id: String = Id,
+///         pub region: Region = Region,
+///         pub latitude: f64 = Latitude,
+///         pub longitude: f64 = Longitude,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! projection {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident: $type:ty = $column:ident
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $type,
+            )*
+        }
+
+        impl $crate::Projection for $name {
+            const FIELDS: &'static [$crate::Field] = &[$($crate::Field::$column),*];
+
+            fn from_columns(columns: &$crate::Columns) -> Result<Self, $crate::Error> {
+                Ok($name {
+                    $($field: $crate::__column(columns, $crate::Field::$column)?,)*
+                })
+            }
+        }
+    };
+}
+
+projection! {
+    /// The minimal columns that identify an event, e.g. to check which events
+    /// were updated without fetching all columns.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct MinimalEvent {
+        /// See [`crate::AcledEvent::id`].
+        pub id: String = Id,
+        /// See [`crate::AcledEvent::date`].
+        pub date: NaiveDate = Date,
+        /// See [`crate::AcledEvent::timestamp`].
+        pub timestamp: u64 = Timestamp,
+    }
+}
+
+/// Adapter to fetch a [`Projection`] like any other event type.
+pub(crate) struct Projected<P>(pub P);
+
+impl<P: Projection> TryFrom<Columns> for Projected<P> {
+    type Error = Error;
+
+    fn try_from(columns: Columns) -> Result<Self, Self::Error> {
+        P::from_columns(&columns).map(Projected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    #[test]
+    fn minimal_event_test() {
+        let body = br#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI123","event_date":"2024-03-01","timestamp":1709251200}]}"#;
+        let events = serde_json::from_slice::<Response<Columns>>(body)
+            .unwrap()
+            .into::<Projected<MinimalEvent>>()
+            .unwrap();
+        assert_eq!(
+            events[0].0,
+            MinimalEvent {
+                id: "MLI123".into(),
+                date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                timestamp: 1709251200,
+            }
+        );
+
+        let body = br#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI123","event_date":"2024-03-01"}]}"#;
+        let error = serde_json::from_slice::<Response<Columns>>(body)
+            .unwrap()
+            .into::<Projected<MinimalEvent>>()
+            .err()
+            .unwrap();
+        assert!(matches!(error, Error::ParseError(column) if column == "timestamp"));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::precision::{GeoPrecision, TimePrecision};
+use crate::AcledEvent;
+
+/// A rough indication of how reliable the details of an event are, see
+/// [`AcledEvent::quality`]. Ordered from `Low` to `High`, so it can be used
+/// as a filter:
+///
+/// ```
+/// # use acled_api::{AcledEvent, Quality};
+/// # fn example(events: &mut Vec<AcledEvent>) {
+/// events.retain(|event| event.quality() >= Quality::Medium);
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Quality {
+    Low,
+    Medium,
+    High,
+}
+
+impl AcledEvent {
+    /// A score from 0 to 6 based on these heuristics:
+    ///
+    /// - The location is exact (+2) or nearby (+1).
+    /// - The day (+2) or week (+1) of the event is known.
+    /// - Local, subnational or national sources were used (+1).
+    /// - The number of fatalities doesn't look like a rounded estimate (+1),
+    ///   i.e. it is below 100 or not a multiple of 10.
+    pub fn quality_score(&self) -> u8 {
+        let geo = match self.geo_precision {
+            GeoPrecision::Exact => 2,
+            GeoPrecision::Nearby => 1,
+            GeoPrecision::Region => 0,
+        };
+        let time = match self.time_precision {
+            TimePrecision::Day => 2,
+            TimePrecision::Week => 1,
+            TimePrecision::Month => 0,
+        };
+        // Note: Combined scales are separated by `-`, e.g. `National-Regional`.
+        let sources = self.source_scale.split('-').any(|scale| {
+            let scale = scale.trim().to_lowercase();
+            scale.starts_with("local") || scale == "subnational" || scale == "national"
+        });
+        // Note: `u32::is_multiple_of` would require Rust 1.87.
+        #[allow(clippy::manual_is_multiple_of)]
+        let fatalities = self.fatalities < 100 || self.fatalities % 10 != 0;
+        geo + time + u8::from(sources) + u8::from(fatalities)
+    }
+
+    /// The [`Quality`] of the event: `High` for a [`AcledEvent::quality_score`]
+    /// of at least 5, `Medium` for at least 3 and `Low` otherwise.
+    pub fn quality(&self) -> Quality {
+        match self.quality_score() {
+            5.. => Quality::High,
+            3..=4 => Quality::Medium,
+            _ => Quality::Low,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acled::tests::acled_event;
+
+    #[test]
+    fn quality_test() {
+        let mut event = acled_event("MLI1");
+        assert_eq!(event.quality_score(), 6);
+        assert_eq!(event.quality(), Quality::High);
+
+        event.geo_precision = GeoPrecision::Region;
+        event.fatalities = 200;
+        assert_eq!(event.quality(), Quality::Medium);
+
+        event.time_precision = TimePrecision::Month;
+        event.source_scale = "Regional-International".into();
+        assert_eq!(event.quality_score(), 0);
+        assert_eq!(event.quality(), Quality::Low);
+    }
+}
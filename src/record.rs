@@ -0,0 +1,92 @@
+use crate::projection::{parse_column, Columns};
+use crate::Error;
+use std::str::FromStr;
+
+/// A single row with whatever columns were returned, e.g. for columns that
+/// are selected at runtime with [`crate::AcledQuery::fields`]. Columns are
+/// parsed on access.
+///
+/// ```
+/// # use acled_api::{EventRecord, NaiveDate};
+/// # fn example(record: &EventRecord) -> Result<(), acled_api::Error> {
+/// let date = record.get::<NaiveDate>("event_date")?;
+/// let fatalities = record.get::<u32>("fatalities")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventRecord {
+    columns: Columns,
+}
+
+impl EventRecord {
+    /// Parse the column `name`. Fails if the column wasn't returned or
+    /// couldn't be parsed as `T`.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, Error> {
+        parse_column(&self.columns, name)
+    }
+
+    /// Like [`EventRecord::get`], but returns `None` if the column wasn't
+    /// returned.
+    pub fn get_opt<T: FromStr>(&self, name: &str) -> Result<Option<T>, Error> {
+        if self.contains(name) {
+            self.get(name).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Whether the column `name` was returned.
+    pub fn contains(&self, name: &str) -> bool {
+        self.columns.contains_key(name)
+    }
+
+    /// The names of all returned columns.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.columns.keys().map(String::as_str)
+    }
+
+    /// The raw columns.
+    pub fn columns(&self) -> &Columns {
+        &self.columns
+    }
+}
+
+impl TryFrom<Columns> for EventRecord {
+    type Error = Error;
+
+    fn try_from(columns: Columns) -> Result<Self, Self::Error> {
+        Ok(EventRecord { columns })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn record_test() {
+        let body = br#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI123","event_date":"2024-03-01","fatalities":"3","latitude":14.4843}]}"#;
+        let records = serde_json::from_slice::<Response<Columns>>(body)
+            .unwrap()
+            .into::<EventRecord>()
+            .unwrap();
+        let record = &records[0];
+        assert_eq!(record.get::<String>("event_id_cnty").unwrap(), "MLI123");
+        assert_eq!(
+            record.get::<NaiveDate>("event_date").unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+        );
+        assert_eq!(record.get::<u32>("fatalities").unwrap(), 3);
+        assert_eq!(record.get::<f64>("latitude").unwrap(), 14.4843);
+        assert_eq!(record.get_opt::<u64>("timestamp").unwrap(), None);
+        assert!(record.get::<u64>("timestamp").is_err());
+        assert!(record.get::<u32>("event_id_cnty").is_err());
+        assert_eq!(
+            record.names().collect::<Vec<_>>(),
+            vec!["event_date", "event_id_cnty", "fatalities", "latitude"]
+        );
+    }
+}
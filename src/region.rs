@@ -1,6 +1,6 @@
 use strum::{Display, EnumString};
 
-use crate::AsParameter;
+use crate::{sealed, AsParameter};
 
 /// Numeric codes for each region in ACLED data.
 /// <https://apidocs.acleddata.com/acled_endpoint.html#regions>
@@ -55,9 +55,42 @@ pub enum Region {
     Antarctica = 20,
 }
 
+impl Region {
+    /// The region for an ID number.
+    pub fn from_id(id: u32) -> Option<Region> {
+        Some(match id {
+            1 => Self::WesternAfrica,
+            2 => Self::MiddleAfrica,
+            3 => Self::EasternAfrica,
+            4 => Self::SouthernAfrica,
+            5 => Self::NorthernAfrica,
+            7 => Self::SouthAsia,
+            9 => Self::SoutheastAsia,
+            11 => Self::MiddleEast,
+            12 => Self::Europe,
+            13 => Self::CaucasusAndCentralAsia,
+            14 => Self::CentralAmerica,
+            15 => Self::SouthAmerica,
+            16 => Self::Caribbean,
+            17 => Self::EastAsia,
+            18 => Self::NorthAmerica,
+            19 => Self::Oceania,
+            20 => Self::Antarctica,
+            _ => return None,
+        })
+    }
+
+    /// The ID number of this region.
+    pub fn id(&self) -> u32 {
+        *self as u32
+    }
+}
+
 impl AsParameter for Region {
     fn as_parameter(&self) -> String {
         // Note: The query strings use the region ID number.
-        (*self as usize).to_string()
+        self.id().to_string()
     }
 }
+
+impl sealed::Sealed for Region {}
@@ -0,0 +1,161 @@
+use crate::{page::Paging, region::Region, response::RegionData, Error, Where};
+use chrono::NaiveDate;
+
+/// This struct is used for specifying the query parameters for the `region`
+/// endpoint. See <https://apidocs.acleddata.com/region_endpoint.html#query-filters>.
+///
+/// All fields are optional and can be default initialized.
+///
+/// ```
+/// use acled_api::{Region, RegionQuery, Where};
+///
+/// let query = RegionQuery {
+///   region: Where::Matches(Region::WesternAfrica),
+///   ..Default::default()
+/// };
+/// ```
+#[derive(Default)]
+pub struct RegionQuery {
+    pub region: Where<Region>,
+    /// Sent as `region_name`.
+    pub name: Where<String>,
+    pub first_event_date: Where<NaiveDate>,
+    pub last_event_date: Where<NaiveDate>,
+    pub event_count: Where<u32>,
+    /// The maximum number of rows returned per page. Defaults to the
+    /// API's own limit of 5000 rows when unset.
+    pub limit: Option<u32>,
+    /// Stop fetching once at least this many regions have been received.
+    /// Any surplus regions are discarded. This is not sent to the API.
+    pub max_results: Option<usize>,
+    /// Stop fetching after this many pages. This is not sent to the API.
+    pub max_pages: Option<u32>,
+}
+
+impl RegionQuery {
+    pub(crate) fn paging(&self) -> Paging {
+        Paging {
+            limit: self.limit,
+            max_results: self.max_results,
+            max_pages: self.max_pages,
+        }
+    }
+
+    /// The query parameters that are sent to the API, sorted by name and
+    /// value. This doesn't include the credentials or the `page` parameter.
+    pub fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = self.as_parameters();
+        pairs.sort();
+        pairs
+    }
+
+    pub(crate) fn as_parameters(&self) -> Vec<(String, String)> {
+        let RegionQuery {
+            region,
+            name,
+            first_event_date,
+            last_event_date,
+            event_count,
+            limit,
+            max_results: _,
+            max_pages: _,
+        } = self;
+
+        let mut parameters = Vec::new();
+        parameters.extend_from_slice(&region.as_parameters("region"));
+        parameters.extend_from_slice(&name.as_parameters("region_name"));
+        parameters.extend_from_slice(&first_event_date.as_parameters("first_event_date"));
+        parameters.extend_from_slice(&last_event_date.as_parameters("last_event_date"));
+        parameters.extend_from_slice(&event_count.as_parameters("event_count"));
+        if let Some(limit) = limit {
+            parameters.push(("limit".into(), limit.to_string()));
+        }
+        parameters
+    }
+}
+
+/// A region returned by the `region` endpoint.
+///
+/// Descriptions based on <https://apidocs.acleddata.com/region_endpoint.html>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RegionInfo {
+    /// The ID number of the region.
+    ///
+    /// Renamed from `region`.
+    pub id: u32,
+    /// The name of the region.
+    ///
+    /// Renamed from `region_name`.
+    pub name: String,
+    /// The date of the earliest event in the region.
+    pub first_event_date: NaiveDate,
+    /// The date of the latest event in the region.
+    pub last_event_date: NaiveDate,
+    /// The number of events in the region.
+    pub event_count: u32,
+}
+
+impl RegionInfo {
+    /// The [`Region`] with the same ID number, if this crate knows it.
+    pub fn region(&self) -> Option<Region> {
+        Region::from_id(self.id)
+    }
+
+    /// Whether [`Region`] has a variant with the same ID number and name.
+    /// Returns `false` if the regions of the API and this crate have drifted
+    /// apart, e.g. because a region was added or renamed.
+    pub fn is_consistent(&self) -> bool {
+        self.region()
+            .is_some_and(|region| region.to_string() == self.name)
+    }
+}
+
+impl TryFrom<RegionData> for RegionInfo {
+    type Error = Error;
+
+    fn try_from(data: RegionData) -> Result<Self, Self::Error> {
+        Ok(RegionInfo {
+            id: data
+                .region
+                .parse()
+                .map_err(|_| Error::ParseError("region".into()))?,
+            name: data.region_name,
+            first_event_date: NaiveDate::parse_from_str(&data.first_event_date, "%Y-%m-%d")
+                .map_err(|_| Error::ParseError("first_event_date".into()))?,
+            last_event_date: NaiveDate::parse_from_str(&data.last_event_date, "%Y-%m-%d")
+                .map_err(|_| Error::ParseError("last_event_date".into()))?,
+            event_count: data
+                .event_count
+                .parse()
+                .map_err(|_| Error::ParseError("event_count".into()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    #[test]
+    fn query_test() {
+        let query = RegionQuery {
+            region: Where::Matches(Region::Europe),
+            ..Default::default()
+        };
+        assert_eq!(query.as_parameters(), vec![("region".into(), "12".into())]);
+    }
+
+    #[test]
+    fn region_info_test() {
+        let body = br#"{"success":true,"count":2,"data":[{"region":"1","region_name":"Western Africa","first_event_date":"1997-01-01","last_event_date":"2024-03-01","event_count":"123456"},{"region":"21","region_name":"Arctic","first_event_date":"2024-01-01","last_event_date":"2024-03-01","event_count":"1"}]}"#;
+        let regions = serde_json::from_slice::<Response<RegionData>>(body)
+            .unwrap()
+            .into::<RegionInfo>()
+            .unwrap();
+        assert_eq!(regions[0].region(), Some(Region::WesternAfrica));
+        assert!(regions[0].is_consistent());
+        assert_eq!(regions[1].region(), None);
+        assert!(!regions[1].is_consistent());
+    }
+}
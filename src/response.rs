@@ -1,5 +1,6 @@
 use crate::Error;
 use serde::Deserialize;
+use std::collections::HashMap;
 
 /*
 // XXX: The docs are lying, the status can be either a string or int.
@@ -11,6 +12,51 @@ enum Status {
 }
 */
 
+/// The columns of the `acled` endpoint that are parsed into [`AcledData`].
+/// Keep this in sync with the struct below.
+pub(crate) const ACLED_FIELDS: &[&str] = &[
+    "event_id_cnty",
+    "event_date",
+    "timestamp",
+    "disorder_type",
+    "event_type",
+    "sub_event_type",
+    "country",
+    "region",
+    "admin1",
+    "latitude",
+    "longitude",
+    "notes",
+    "civilian_targeting",
+    "actor1",
+    "assoc_actor_1",
+    "inter1",
+    "actor2",
+    "assoc_actor_2",
+    "inter2",
+    "interaction",
+    "fatalities",
+    "source",
+    "source_scale",
+    "tags",
+    "admin2",
+    "admin3",
+    "location",
+    "geo_precision",
+    "time_precision",
+    "year",
+    "iso",
+];
+
+/// Columns of the `acled` endpoint that are only returned on request, see
+/// [`crate::AcledQuery::population`].
+pub(crate) const ACLED_OPTIONAL_FIELDS: &[&str] = &[
+    "population_1km",
+    "population_2km",
+    "population_5km",
+    "population_best",
+];
+
 #[derive(Deserialize, Debug)]
 pub(crate) struct AcledData {
     pub event_id_cnty: String,
@@ -29,6 +75,96 @@ pub(crate) struct AcledData {
     pub longitude: String,
 
     pub notes: String,
+    pub civilian_targeting: String,
+
+    pub actor1: String,
+    pub assoc_actor_1: String,
+    pub inter1: String,
+    pub actor2: String,
+    pub assoc_actor_2: String,
+    pub inter2: String,
+    pub interaction: String,
+
+    pub fatalities: String,
+
+    pub source: String,
+    pub source_scale: String,
+    pub tags: String,
+
+    pub admin2: String,
+    pub admin3: String,
+    pub location: String,
+
+    pub geo_precision: String,
+    pub time_precision: String,
+
+    pub year: String,
+    pub iso: String,
+    #[serde(default)]
+    pub population_1km: Option<String>,
+    #[serde(default)]
+    pub population_2km: Option<String>,
+    #[serde(default)]
+    pub population_5km: Option<String>,
+    #[serde(default)]
+    pub population_best: Option<String>,
+
+    /// Columns that aren't parsed (yet).
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Like [`AcledData`], but every column is optional, for queries that only
+/// select some columns.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub(crate) struct SparseData {
+    pub event_id_cnty: Option<String>,
+    pub event_date: Option<String>,
+    pub timestamp: Option<String>,
+
+    pub disorder_type: Option<String>,
+    pub event_type: Option<String>,
+    pub sub_event_type: Option<String>,
+
+    pub country: Option<String>,
+    pub region: Option<String>,
+    pub admin1: Option<String>,
+
+    pub latitude: Option<String>,
+    pub longitude: Option<String>,
+
+    pub notes: Option<String>,
+    pub civilian_targeting: Option<String>,
+
+    pub year: Option<String>,
+    pub iso: Option<String>,
+
+    pub actor1: Option<String>,
+    pub assoc_actor_1: Option<String>,
+    pub inter1: Option<String>,
+    pub actor2: Option<String>,
+    pub assoc_actor_2: Option<String>,
+    pub inter2: Option<String>,
+    pub interaction: Option<String>,
+
+    pub fatalities: Option<String>,
+
+    pub source: Option<String>,
+    pub source_scale: Option<String>,
+    pub tags: Option<String>,
+
+    pub admin2: Option<String>,
+    pub admin3: Option<String>,
+    pub location: Option<String>,
+
+    pub geo_precision: Option<String>,
+    pub time_precision: Option<String>,
+
+    pub population_1km: Option<String>,
+    pub population_2km: Option<String>,
+    pub population_5km: Option<String>,
+    pub population_best: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -37,6 +173,67 @@ pub(crate) struct DeletedData {
     pub deleted_timestamp: String,
 }
 
+#[derive(Deserialize, Debug)]
+pub(crate) struct ActorData {
+    pub actor_name: String,
+    pub first_event_date: String,
+    pub last_event_date: String,
+    pub event_count: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ActorTypeData {
+    pub actor_type_id: String,
+    pub actor_type_name: String,
+    pub first_event_date: String,
+    pub last_event_date: String,
+    pub event_count: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct CountryData {
+    pub country: String,
+    pub iso: String,
+    pub iso3: String,
+    pub first_event_date: String,
+    pub last_event_date: String,
+    pub event_count: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct RegionData {
+    pub region: String,
+    pub region_name: String,
+    pub first_event_date: String,
+    pub last_event_date: String,
+    pub event_count: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct CastData {
+    pub country: String,
+    pub admin1: String,
+    pub month: String,
+    pub year: String,
+
+    pub total_forecast: String,
+    pub battles_forecast: String,
+    pub erv_forecast: String,
+    pub vac_forecast: String,
+
+    // Note: The observed counts are only known once the month is over.
+    #[serde(default)]
+    pub total_observed: Option<String>,
+    #[serde(default)]
+    pub battles_observed: Option<String>,
+    #[serde(default)]
+    pub erv_observed: Option<String>,
+    #[serde(default)]
+    pub vac_observed: Option<String>,
+
+    pub timestamp: String,
+}
+
 #[derive(Deserialize, Debug)]
 pub(crate) struct ErrorData {
     // status: String,
@@ -62,23 +259,41 @@ pub(crate) enum Response<T> {
 
 impl<T> Response<T> {
     pub(crate) fn into<S: std::convert::TryFrom<T, Error = Error>>(self) -> Result<Vec<S>, Error> {
+        self.into_data()?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
+    }
+
+    /// The rows of a successful response. Inconsistent responses, e.g. with a
+    /// `count` that doesn't match the number of rows, are a
+    /// [`Error::ParseError`].
+    pub(crate) fn into_data(self) -> Result<Vec<T>, Error> {
         match self {
             Self::Data {
                 data,
                 success,
                 count,
             } => {
-                assert!(success);
-                assert_eq!(count as usize, data.len());
-                data.into_iter().map(TryInto::try_into).collect()
+                if !success {
+                    return Err(Error::ParseError("success".into()));
+                }
+                if count as usize != data.len() {
+                    return Err(Error::ParseError(format!(
+                        "count: {count}, but {} rows",
+                        data.len()
+                    )));
+                }
+                Ok(data)
             }
             Self::Error {
                 success,
                 count,
                 error,
             } => {
-                assert!(!success);
-                assert_eq!(count, 0);
+                if success || count != 0 {
+                    return Err(Error::ParseError("success".into()));
+                }
                 Err(Error::APIError {
                     message: error.message,
                 })
@@ -86,3 +301,39 @@ impl<T> Response<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn into_data(body: &str) -> Result<Vec<serde_json::Value>, Error> {
+        serde_json::from_str::<Response<serde_json::Value>>(body)
+            .unwrap()
+            .into_data()
+    }
+
+    #[test]
+    fn into_data_test() {
+        assert_eq!(
+            into_data(r#"{"success":true,"count":1,"data":[{}]}"#).unwrap(),
+            vec![serde_json::json!({})]
+        );
+        assert!(matches!(
+            into_data(r#"{"success":false,"count":0,"error":{"message":"Invalid key"}}"#),
+            Err(Error::APIError { message }) if message == "Invalid key"
+        ));
+
+        assert!(matches!(
+            into_data(r#"{"success":true,"count":2,"data":[{}]}"#),
+            Err(Error::ParseError(message)) if message == "count: 2, but 1 rows"
+        ));
+        assert!(matches!(
+            into_data(r#"{"success":false,"count":1,"data":[{}]}"#),
+            Err(Error::ParseError(_))
+        ));
+        assert!(matches!(
+            into_data(r#"{"success":true,"count":0,"error":{"message":""}}"#),
+            Err(Error::ParseError(_))
+        ));
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+/// The number of retries that are left for a single operation, e.g. one call
+/// to [`crate::Api::get_acled`]. Shared by all requests of that operation,
+/// including concurrent ones, so a flaky connection can't retry every page
+/// over and over.
+#[derive(Debug)]
+pub(crate) struct RetryBudget {
+    limit: u32,
+    used: AtomicU32,
+}
+
+impl RetryBudget {
+    pub(crate) fn new(limit: u32) -> RetryBudget {
+        RetryBudget {
+            limit,
+            used: AtomicU32::new(0),
+        }
+    }
+
+    /// Take one retry out of the budget. Returns how long to wait before
+    /// retrying, or `None` if the budget is used up.
+    pub(crate) fn take(&self) -> Option<Duration> {
+        let attempt = self
+            .used
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                (used < self.limit).then_some(used + 1)
+            })
+            .ok()?;
+        // Exponential backoff: 1s, 2s, 4s, ... up to 32s.
+        Some(Duration::from_secs(1 << attempt.min(5)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_test() {
+        let budget = RetryBudget::new(2);
+        assert_eq!(budget.take(), Some(Duration::from_secs(1)));
+        assert_eq!(budget.take(), Some(Duration::from_secs(2)));
+        assert_eq!(budget.take(), None);
+
+        assert_eq!(RetryBudget::new(0).take(), None);
+    }
+}
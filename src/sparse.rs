@@ -0,0 +1,182 @@
+use crate::acled::{
+    non_empty, parse_civilian_targeting, parse_column, parse_coordinate, parse_date,
+    parse_disorder_type, parse_event_type, parse_geo_precision, parse_inter1, parse_inter2,
+    parse_interaction, parse_population, parse_sub_event_type, parse_time_precision, split_tags,
+};
+use crate::disorder_type::DisorderType;
+use crate::event_type::{EventType, SubEventType};
+use crate::inter::{Inter, Interaction};
+use crate::precision::{GeoPrecision, TimePrecision};
+use crate::region::Region;
+use crate::response::SparseData;
+use crate::Error;
+use chrono::NaiveDate;
+
+/// An event returned by the `acled` endpoint when only some columns were
+/// requested with [`crate::AcledQuery::fields`]. Columns that weren't
+/// returned are `None`. See [`crate::AcledEvent`] for the descriptions of
+/// the fields.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SparseEvent {
+    pub id: Option<String>,
+    pub timestamp: Option<u64>,
+    pub date: Option<NaiveDate>,
+    pub year: Option<u32>,
+    pub event_type: Option<EventType>,
+    pub sub_event_type: Option<SubEventType>,
+    pub disorder_type: Option<DisorderType>,
+    pub region: Option<Region>,
+    pub country: Option<String>,
+    pub iso: Option<u32>,
+    pub administrative_region: Option<String>,
+    /// `Some(None)` if the column was returned, but empty.
+    pub admin2: Option<Option<String>>,
+    /// `Some(None)` if the column was returned, but empty.
+    pub admin3: Option<Option<String>>,
+    /// `Some(None)` if the column was returned, but empty.
+    pub location: Option<Option<String>>,
+    pub geo_precision: Option<GeoPrecision>,
+    pub time_precision: Option<TimePrecision>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub note: Option<String>,
+    pub civilian_targeting: Option<bool>,
+    pub fatalities: Option<u32>,
+    pub actor1: Option<String>,
+    pub assoc_actor_1: Option<String>,
+    pub inter1: Option<Inter>,
+    pub actor2: Option<String>,
+    pub assoc_actor_2: Option<String>,
+    /// `Some(None)` if the column was returned, but the event has no second
+    /// actor.
+    pub inter2: Option<Option<Inter>>,
+    pub interaction: Option<Interaction>,
+    pub source: Option<String>,
+    pub source_scale: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub population_1km: Option<u64>,
+    pub population_2km: Option<u64>,
+    pub population_5km: Option<u64>,
+    pub population_best: Option<u64>,
+}
+
+/// Parse a column with `parse`, if it was returned.
+fn optional<T>(
+    value: Option<String>,
+    parse: impl FnOnce(&str) -> Result<T, Error>,
+) -> Result<Option<T>, Error> {
+    value.map(|value| parse(&value)).transpose()
+}
+
+impl TryFrom<SparseData> for SparseEvent {
+    type Error = Error;
+
+    fn try_from(data: SparseData) -> Result<Self, Self::Error> {
+        let inter1 = optional(data.inter1, parse_inter1)?;
+        let inter2 = optional(data.inter2, parse_inter2)?;
+        let interaction = optional(data.interaction, parse_interaction)?.and_then(|interaction| {
+            // Note: Fall back to the actor categories when the column is
+            // empty, like `AcledEvent` does.
+            interaction.or(inter1
+                .zip(inter2)
+                .map(|(inter1, inter2)| Interaction::new(inter1, inter2)))
+        });
+
+        Ok(SparseEvent {
+            id: data.event_id_cnty,
+            timestamp: optional(data.timestamp, |value| parse_column(value, "timestamp"))?,
+            date: optional(data.event_date, parse_date)?,
+            year: optional(data.year, |value| parse_column(value, "year"))?,
+            event_type: data.event_type.as_deref().map(parse_event_type),
+            sub_event_type: data.sub_event_type.as_deref().map(parse_sub_event_type),
+            disorder_type: data.disorder_type.as_deref().map(parse_disorder_type),
+            region: optional(data.region, |value| parse_column(value, "region"))?,
+            country: data.country,
+            iso: optional(data.iso, |value| parse_column(value, "iso"))?,
+            administrative_region: data.admin1,
+            admin2: data.admin2.map(non_empty),
+            admin3: data.admin3.map(non_empty),
+            location: data.location.map(non_empty),
+            geo_precision: optional(data.geo_precision, parse_geo_precision)?,
+            time_precision: optional(data.time_precision, parse_time_precision)?,
+            latitude: optional(data.latitude, |value| parse_coordinate(value, "latitude"))?,
+            longitude: optional(data.longitude, |value| parse_coordinate(value, "longitude"))?,
+            note: data.notes,
+            civilian_targeting: optional(data.civilian_targeting, parse_civilian_targeting)?,
+            fatalities: optional(data.fatalities, |value| parse_column(value, "fatalities"))?,
+            actor1: data.actor1,
+            assoc_actor_1: data.assoc_actor_1,
+            inter1,
+            actor2: data.actor2,
+            assoc_actor_2: data.assoc_actor_2,
+            inter2,
+            interaction,
+            source: data.source,
+            source_scale: data.source_scale,
+            tags: data.tags.map(|tags| split_tags(&tags)),
+            population_1km: parse_population(data.population_1km.as_deref()),
+            population_2km: parse_population(data.population_2km.as_deref()),
+            population_5km: parse_population(data.population_5km.as_deref()),
+            population_best: parse_population(data.population_best.as_deref()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    #[test]
+    fn sparse_test() {
+        let body = br#"{"success":true,"count":1,"data":[{"event_id_cnty":"MLI123","event_date":"2024-03-01","latitude":"14.4843","longitude":"-4.1960"}]}"#;
+        let events = serde_json::from_slice::<Response<SparseData>>(body)
+            .unwrap()
+            .into::<SparseEvent>()
+            .unwrap();
+        assert_eq!(events[0].id.as_deref(), Some("MLI123"));
+        assert_eq!(events[0].date, NaiveDate::from_ymd_opt(2024, 3, 1));
+        assert_eq!(events[0].latitude, Some(14.4843));
+        assert_eq!(events[0].timestamp, None);
+        assert_eq!(events[0].region, None);
+    }
+
+    #[test]
+    fn sparse_full_row_test() {
+        let data: SparseData =
+            serde_json::from_value(crate::acled::tests::acled_row("MLI123", 1710025200)).unwrap();
+        let event = SparseEvent::try_from(data).unwrap();
+        assert_eq!(event.event_type, Some(EventType::Battles));
+        assert_eq!(event.disorder_type, Some(DisorderType::PoliticalViolence));
+        assert_eq!(event.fatalities, Some(3));
+        assert_eq!(event.inter1, Some(Inter::StateForces));
+        assert_eq!(event.inter2, Some(Some(Inter::RebelGroups)));
+        assert_eq!(
+            event.interaction,
+            Some(Interaction::new(
+                Inter::StateForces,
+                Some(Inter::RebelGroups)
+            ))
+        );
+        assert_eq!(event.admin2, Some(Some("Djenne".into())));
+        assert_eq!(event.admin3, Some(None));
+        assert_eq!(event.geo_precision, Some(GeoPrecision::Exact));
+        assert_eq!(event.iso, Some(466));
+        assert_eq!(
+            event.tags,
+            Some(vec![
+                "crowd size=no report".into(),
+                "local administrators".into()
+            ])
+        );
+        assert_eq!(event.population_best, None);
+
+        let data = SparseData {
+            event_type: Some("Battles".into()),
+            sub_event_type: Some("Peaceful protest".into()),
+            ..Default::default()
+        };
+        let event = SparseEvent::try_from(data).unwrap();
+        assert_eq!(event.sub_event_type, Some(SubEventType::PeacefulProtest));
+    }
+}
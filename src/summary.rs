@@ -0,0 +1,98 @@
+use crate::{AcledEvent, EventKey};
+use std::collections::HashMap;
+
+/// Produces a short version of an event note, e.g. for alert messages or
+/// table views. See [`summarize_notes`].
+pub trait Summarizer {
+    fn summarize(&self, note: &str) -> String;
+}
+
+/// The default [`Summarizer`]: the first sentence of the note, truncated at
+/// a word boundary to at most `max_chars` characters (including the `…`).
+#[derive(Copy, Clone, Debug)]
+pub struct FirstSentence {
+    pub max_chars: usize,
+}
+
+impl Default for FirstSentence {
+    fn default() -> Self {
+        FirstSentence { max_chars: 160 }
+    }
+}
+
+impl Summarizer for FirstSentence {
+    fn summarize(&self, note: &str) -> String {
+        let note = note.trim();
+        // Note: Notes usually start with the date, e.g. "On 1 March 2024, ...",
+        // so only a period followed by whitespace ends a sentence.
+        let sentence = note
+            .match_indices(". ")
+            .next()
+            .map_or(note, |(index, _)| &note[..=index]);
+
+        if sentence.chars().count() <= self.max_chars {
+            return sentence.to_owned();
+        }
+
+        // Leave room for the ellipsis and cut at a char boundary.
+        let limit = self.max_chars.saturating_sub(1);
+        let end = sentence
+            .char_indices()
+            .nth(limit)
+            .map_or(sentence.len(), |(index, _)| index);
+        let truncated = &sentence[..end];
+        // Prefer cutting at the last whitespace, unless that drops everything.
+        let truncated = match truncated.rfind(char::is_whitespace) {
+            Some(index) if index > 0 => &truncated[..index],
+            _ => truncated,
+        };
+        format!("{}…", truncated.trim_end_matches([' ', ',', ';', ':']))
+    }
+}
+
+/// Summarize the note of every event with `summarizer`, keyed by
+/// [`AcledEvent::key`].
+pub fn summarize_notes(
+    events: &[AcledEvent],
+    summarizer: &impl Summarizer,
+) -> HashMap<EventKey, String> {
+    events
+        .iter()
+        .map(|event| (event.key(), summarizer.summarize(&event.note)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_sentence_test() {
+        let summarizer = FirstSentence { max_chars: 40 };
+        assert_eq!(
+            summarizer.summarize("On 1 March 2024, clashes occurred. 3 fatalities."),
+            "On 1 March 2024, clashes occurred."
+        );
+        assert_eq!(
+            summarizer
+                .summarize("On 1 March 2024, military forces clashed with militants near Djenne."),
+            "On 1 March 2024, military forces…"
+        );
+        assert_eq!(
+            FirstSentence { max_chars: 5 }.summarize("Überfall"),
+            "Über…"
+        );
+        assert_eq!(summarizer.summarize(""), "");
+    }
+
+    #[test]
+    fn summarize_notes_test() {
+        let mut event = crate::acled::tests::acled_event("MLI1");
+        event.note = "On 1 March 2024, clashes occurred. 3 fatalities.".into();
+        let short_notes = summarize_notes(std::slice::from_ref(&event), &FirstSentence::default());
+        assert_eq!(
+            short_notes[&event.key()],
+            "On 1 March 2024, clashes occurred."
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{AcledEvent, EventKey};
+
+/// User-defined keyword rules that tag events by their note and actors,
+/// e.g. to mark all events mentioning a "drone strike". See [`tag_events`].
+///
+/// ```
+/// use acled_api::Taxonomy;
+///
+/// let taxonomy = Taxonomy::new()
+///     .rule("drone", ["drone strike", "UAV"])
+///     .rule("school", ["school", "university"]);
+/// assert_eq!(
+///     taxonomy.tags_for("Unidentified UAV hit a school."),
+///     vec!["drone", "school"]
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Taxonomy {
+    rules: Vec<Rule>,
+}
+
+#[derive(Clone, Debug)]
+struct Rule {
+    tag: String,
+    /// Lowercase keywords.
+    keywords: Vec<String>,
+}
+
+impl Taxonomy {
+    pub fn new() -> Taxonomy {
+        Taxonomy::default()
+    }
+
+    /// Add a rule that applies `tag` when any of the `keywords` appears as
+    /// whole words, ignoring case.
+    pub fn rule<K: Into<String>>(
+        mut self,
+        tag: impl Into<String>,
+        keywords: impl IntoIterator<Item = K>,
+    ) -> Taxonomy {
+        self.rules.push(Rule {
+            tag: tag.into(),
+            keywords: keywords
+                .into_iter()
+                .map(|keyword| keyword.into().to_lowercase())
+                .collect(),
+        });
+        self
+    }
+
+    /// The tags of all rules matching `text`, in the order the rules were
+    /// added.
+    pub fn tags_for(&self, text: &str) -> Vec<String> {
+        self.matching(&[text])
+    }
+
+    /// The tags of all rules matching the note or one of the actors of
+    /// `event`.
+    pub fn tags_for_event(&self, event: &AcledEvent) -> Vec<String> {
+        self.matching(&[
+            &event.note,
+            &event.actor1,
+            &event.assoc_actor_1,
+            &event.actor2,
+            &event.assoc_actor_2,
+        ])
+    }
+
+    fn matching(&self, texts: &[&str]) -> Vec<String> {
+        let texts: Vec<String> = texts.iter().map(|text| text.to_lowercase()).collect();
+        self.rules
+            .iter()
+            .filter(|rule| {
+                rule.keywords
+                    .iter()
+                    .any(|keyword| texts.iter().any(|text| contains_word(text, keyword)))
+            })
+            .map(|rule| rule.tag.clone())
+            .collect()
+    }
+}
+
+/// Whether `keyword` appears in `text` and isn't part of a longer word.
+fn contains_word(text: &str, keyword: &str) -> bool {
+    if keyword.is_empty() {
+        return false;
+    }
+    text.match_indices(keyword).any(|(start, _)| {
+        let end = start + keyword.len();
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// The tags of every event, in the same order as `events` and keyed by
+/// [`AcledEvent::key`]. See [`Taxonomy::tags_for_event`].
+pub fn tag_events(events: &[AcledEvent], taxonomy: &Taxonomy) -> Vec<(EventKey, Vec<String>)> {
+    events
+        .iter()
+        .map(|event| (event.key(), taxonomy.tags_for_event(event)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acled::tests::acled_event;
+
+    #[test]
+    fn taxonomy_test() {
+        let taxonomy = Taxonomy::new()
+            .rule("election", ["election", "ballot"])
+            .rule("military", ["military forces"])
+            .rule("art", ["art"]);
+        assert_eq!(
+            taxonomy.tags_for("Protest against the ELECTION results."),
+            vec!["election"]
+        );
+        assert_eq!(
+            taxonomy.tags_for("Clashes with armed militants."),
+            Vec::<String>::new()
+        );
+
+        let event = acled_event("MLI1");
+        assert_eq!(
+            tag_events(std::slice::from_ref(&event), &taxonomy),
+            vec![(event.key(), vec!["military".into()])]
+        );
+    }
+}
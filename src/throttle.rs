@@ -0,0 +1,65 @@
+use std::io::Read;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Limits the download bandwidth of all requests made by an [`crate::Api`],
+/// see [`crate::Api::with_max_bandwidth`].
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    bytes_per_second: u64,
+    /// The time at which the next chunk may be read.
+    next: Mutex<Instant>,
+}
+
+/// The size of the chunks in which response bodies are read.
+const CHUNK_SIZE: usize = 16 * 1024;
+
+impl Throttle {
+    pub(crate) fn new(bytes_per_second: u64) -> Throttle {
+        Throttle {
+            bytes_per_second: bytes_per_second.max(1),
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Read everything from `reader`, waiting between chunks so that the
+    /// combined rate of all readers stays below the limit.
+    pub(crate) fn read_to_end(&self, mut reader: impl Read) -> std::io::Result<Vec<u8>> {
+        let mut body = Vec::new();
+        let mut chunk = vec![0; CHUNK_SIZE];
+        loop {
+            let read = reader.read(&mut chunk)?;
+            if read == 0 {
+                return Ok(body);
+            }
+            body.extend_from_slice(&chunk[..read]);
+            self.wait(read);
+        }
+    }
+
+    fn wait(&self, bytes: usize) {
+        let duration = Duration::from_secs_f64(bytes as f64 / self.bytes_per_second as f64);
+        let until = {
+            let mut next = self.next.lock().unwrap_or_else(|error| error.into_inner());
+            let start = (*next).max(Instant::now());
+            *next = start + duration;
+            *next
+        };
+        std::thread::sleep(until.saturating_duration_since(Instant::now()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttle_test() {
+        let throttle = Throttle::new(1024 * 1024);
+        let data = vec![7u8; 64 * 1024];
+
+        let start = Instant::now();
+        assert_eq!(throttle.read_to_end(&data[..]).unwrap(), data);
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{AcledEvent, Error, EventKey};
+use std::collections::HashMap;
+
+/// A translation backend for event notes. See [`translate_notes`].
+pub trait Translator {
+    /// Translate every note into `target_lang`, e.g. `"fr"`. Has to return
+    /// exactly one translation per note, in the same order. Backend errors
+    /// can be reported with [`Error::TranslationError`].
+    fn translate(&self, notes: &[&str], target_lang: &str) -> Result<Vec<String>, Error>;
+
+    /// The maximum number of notes passed to a single
+    /// [`Translator::translate`] call.
+    fn batch_size(&self) -> usize {
+        50
+    }
+}
+
+/// A translated [`AcledEvent::note`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TranslatedNote {
+    /// The language passed to [`translate_notes`].
+    pub language: String,
+    pub text: String,
+}
+
+/// Translate the notes of all events in batches. The translations are
+/// keyed by [`AcledEvent::key`], events with an empty note are skipped.
+pub fn translate_notes(
+    events: &[AcledEvent],
+    target_lang: &str,
+    translator: &impl Translator,
+) -> Result<HashMap<EventKey, TranslatedNote>, Error> {
+    let pending: Vec<&AcledEvent> = events
+        .iter()
+        .filter(|event| !event.note.is_empty())
+        .collect();
+
+    let mut translated = HashMap::new();
+    for batch in pending.chunks(translator.batch_size().max(1)) {
+        let notes: Vec<&str> = batch.iter().map(|event| event.note.as_str()).collect();
+        let translations = translator.translate(&notes, target_lang)?;
+        if translations.len() != batch.len() {
+            return Err(Error::TranslationError(format!(
+                "expected {} translations, got {}",
+                batch.len(),
+                translations.len()
+            )));
+        }
+
+        for (event, text) in batch.iter().zip(translations) {
+            translated.insert(
+                event.key(),
+                TranslatedNote {
+                    language: target_lang.to_owned(),
+                    text,
+                },
+            );
+        }
+    }
+    Ok(translated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acled::tests::acled_event;
+    use std::cell::Cell;
+
+    struct Uppercase {
+        calls: Cell<usize>,
+    }
+
+    impl Translator for Uppercase {
+        fn translate(&self, notes: &[&str], _target_lang: &str) -> Result<Vec<String>, Error> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(notes.iter().map(|note| note.to_uppercase()).collect())
+        }
+
+        fn batch_size(&self) -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn translate_test() {
+        let mut events: Vec<_> = ["MLI1", "MLI2", "MLI3", "MLI4"]
+            .into_iter()
+            .map(|id| {
+                let mut event = acled_event(id);
+                event.note = format!("note {id}");
+                event
+            })
+            .collect();
+        events[1].note.clear();
+
+        let translator = Uppercase {
+            calls: Cell::new(0),
+        };
+        let translated = translate_notes(&events, "en", &translator).unwrap();
+        assert_eq!(translator.calls.get(), 2);
+        assert_eq!(
+            translated.get(&events[0].key()),
+            Some(&TranslatedNote {
+                language: "en".into(),
+                text: "NOTE MLI1".into(),
+            })
+        );
+        assert_eq!(translated.get(&events[1].key()), None);
+        assert_eq!(translated[&events[3].key()].text, "NOTE MLI4");
+    }
+}
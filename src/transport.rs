@@ -0,0 +1,209 @@
+use crate::Error;
+use serde::de::DeserializeOwned;
+use std::io::Read;
+use std::sync::Arc;
+use url::Url;
+
+/// The HTTP methods used by this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+/// A single HTTP request, see [`HttpTransport`].
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    /// Headers in addition to the ones the transport sends by itself.
+    pub headers: Vec<(String, String)>,
+    /// The request body, if any. Only used for [`Method::Post`].
+    pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    pub(crate) fn get(url: Url, headers: Vec<(String, String)>) -> HttpRequest {
+        HttpRequest {
+            method: Method::Get,
+            url,
+            headers,
+            body: None,
+        }
+    }
+
+    /// A `POST` request with an `application/x-www-form-urlencoded` body.
+    pub(crate) fn post_form(url: Url, form: &[(&str, &str)]) -> HttpRequest {
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(form)
+            .finish();
+        HttpRequest {
+            method: Method::Post,
+            url,
+            headers: vec![(
+                "Content-Type".into(),
+                "application/x-www-form-urlencoded".into(),
+            )],
+            body: Some(body.into_bytes()),
+        }
+    }
+
+    /// The value of the header `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// The response to an [`HttpRequest`]. The body is read lazily, so it can be
+/// throttled, see [`crate::Api::with_max_bandwidth`].
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: Box<dyn Read + Send>,
+}
+
+impl HttpResponse {
+    /// A response with a body that is already in memory.
+    pub fn from_bytes(status: u16, body: impl Into<Vec<u8>>) -> HttpResponse {
+        HttpResponse {
+            status,
+            body: Box::new(std::io::Cursor::new(body.into())),
+        }
+    }
+
+    /// Read and parse the whole body as JSON.
+    pub(crate) fn json<T: DeserializeOwned>(self) -> Result<T, Error> {
+        Ok(serde_json::from_reader(self.body)?)
+    }
+}
+
+/// Sends the HTTP requests of an [`crate::Api`], so the crate isn't tied to
+/// a single HTTP library. Responses with any status have to be returned as
+/// [`HttpResponse`], only failures to get a response at all are errors.
+/// Transports should return [`Error::ConnectError`] when the server can't be
+/// reached, see [`Error::is_offline`].
+///
+/// With the default `reqwest` feature, [`ReqwestTransport`] is used unless
+/// another transport is set with [`crate::ApiBuilder::transport`].
+pub trait HttpTransport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        (**self).send(request)
+    }
+}
+
+/// The default [`HttpTransport`], using a blocking [`reqwest`] client.
+#[cfg(feature = "reqwest")]
+#[derive(Default)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::blocking::Client> for ReqwestTransport {
+    fn from(client: reqwest::blocking::Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let method = match request.method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+        };
+        let mut builder = self.client.request(method, request.url);
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().map_err(|error| {
+            if error.is_connect() {
+                Error::ConnectError(Box::new(error))
+            } else {
+                Error::from(error)
+            }
+        })?;
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            body: Box::new(response),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Answers requests with canned responses, in order, and records them.
+    pub(crate) struct MockTransport {
+        responses: Mutex<Vec<(u16, String)>>,
+        pub requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl MockTransport {
+        pub(crate) fn new<S: Into<String>>(responses: Vec<(u16, S)>) -> MockTransport {
+            let mut responses: Vec<_> = responses
+                .into_iter()
+                .map(|(status, body)| (status, body.into()))
+                .collect();
+            responses.reverse();
+            MockTransport {
+                responses: Mutex::new(responses),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl HttpTransport for MockTransport {
+        fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+            self.requests.lock().unwrap().push(request);
+            let (status, body) = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop()
+                .expect("no response left");
+            Ok(HttpResponse::from_bytes(status, body))
+        }
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn offline_test() {
+        // Note: Nothing listens on port 1, so the connection is refused.
+        let request = HttpRequest::get(Url::parse("http://127.0.0.1:1/").unwrap(), Vec::new());
+        let error = ReqwestTransport::default().send(request).err().unwrap();
+        assert!(matches!(error, Error::ConnectError(_)));
+        assert!(error.context("failed to fetch page 1").is_offline());
+
+        assert!(!Error::HttpStatus(503).is_offline());
+    }
+
+    #[test]
+    fn post_form_test() {
+        let request = HttpRequest::post_form(
+            Url::parse("https://example.com/token").unwrap(),
+            &[("username", "foo@example.com"), ("grant_type", "password")],
+        );
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(
+            request.header("content-type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert_eq!(
+            request.body.unwrap(),
+            b"username=foo%40example.com&grant_type=password"
+        );
+    }
+}